@@ -0,0 +1,183 @@
+import express from 'express';
+import cors from 'cors';
+import indicesRoutes from './routes/indices.js';
+import timeRoutes from './routes/time.js';
+import statusRoutes from './routes/status.js';
+import localeRoutes from './routes/locale.js';
+import adminRoutes from './routes/admin.js';
+import { faultInjectionMiddleware } from './utils/faultInjection.js';
+import { timingMiddleware } from './utils/timing.js';
+import { redactSecrets } from './utils/redact.js';
+
+/**
+ * Middleware layers in the order they are applied. Each can be switched off
+ * through config.layers, e.g. { rateLimit: false }.
+ */
+export const LAYERS = ['cors', 'requestId', 'timings', 'json', 'timeout', 'faultInjection', 'rateLimit'];
+
+// CORS configuration for Chrome extension
+const DEFAULT_CORS_OPTIONS = {
+  origin: true, // Temporarily allow all origins for testing
+  methods: ['GET', 'POST', 'PUT', 'DELETE'],
+  allowedHeaders: ['Content-Type', 'Authorization', 'X-API-Key'],
+  credentials: true
+};
+
+/**
+ * Parse a DISABLED_LAYERS style comma-separated list into a layers config
+ * @param {string|undefined} value - e.g. "rateLimit,timeout"
+ * @returns {Object} - {layerName: false} for each listed layer
+ */
+export function parseDisabledLayers(value) {
+  if (!value) return {};
+
+  return Object.fromEntries(
+    value.split(',')
+      .map(name => name.trim())
+      .filter(name => {
+        if (!LAYERS.includes(name)) {
+          console.warn(`⚠️ Unknown middleware layer "${name}" in DISABLED_LAYERS (known: ${LAYERS.join(', ')})`);
+          return false;
+        }
+        return true;
+      })
+      .map(name => [name, false])
+  );
+}
+
+/**
+ * Build the Express app with the production middleware stack and routes
+ * @param {Object} config - Server configuration
+ * @param {Object} config.layers - Per-layer enable flags, all enabled by default
+ * @param {Object} config.corsOptions - Options for the cors layer
+ * @param {number} config.requestTimeoutMs - Timeout for the timeout layer (default: 15000)
+ * @param {number} config.minApiIntervalMs - Minimum interval per IP for the rateLimit layer (default: 1000)
+ * @param {Object|null} config.faultConfig - Settings for the faultInjection layer; the layer is skipped when null
+ * @param {Object} state - Shared services
+ * @param {IndexManager} state.indexManager - Index manager instance
+ * @returns {Express} Express app
+ */
+export function buildApp(config = {}, { indexManager } = {}) {
+  if (!indexManager) {
+    throw new TypeError('buildApp requires an indexManager in its second argument');
+  }
+
+  const {
+    corsOptions = DEFAULT_CORS_OPTIONS,
+    requestTimeoutMs = 15000,
+    minApiIntervalMs = 1000,
+    faultConfig = null
+  } = config;
+  const layers = Object.fromEntries(LAYERS.map(name => [name, config.layers?.[name] !== false]));
+
+  const app = express();
+
+  // Middleware
+  if (layers.cors) {
+    app.use(cors(corsOptions));
+  }
+
+  if (layers.requestId) {
+    app.use((req, res, next) => {
+      const rid = Date.now().toString(36) + Math.random().toString(36).slice(2, 10);
+      req.id = rid;
+      res.setHeader('X-Request-Id', rid);
+      next();
+    });
+  }
+
+  if (layers.timings) {
+    app.use(timingMiddleware);
+  }
+
+  if (layers.json) {
+    app.use(express.json({ limit: '1mb' }));
+  }
+
+  if (layers.timeout) {
+    app.use((req, res, next) => {
+      res.setTimeout(requestTimeoutMs, () => {
+        if (!res.headersSent) {
+          res.status(503).json({
+            error: 'Request timeout',
+            message: 'The server took too long to respond',
+            request_id: req.id
+          });
+        }
+      });
+      next();
+    });
+  }
+
+  const lastRequestByIP = new Map();
+  function minIntervalLimiter(req, res, next) {
+    const ip = req.ip || req.connection?.remoteAddress || 'unknown';
+    const now = Date.now();
+    const last = lastRequestByIP.get(ip) || 0;
+    const elapsed = now - last;
+    if (elapsed < minApiIntervalMs) {
+      const wait = minApiIntervalMs - elapsed;
+      setTimeout(() => {
+        lastRequestByIP.set(ip, Date.now());
+        next();
+      }, wait);
+      return;
+    }
+    lastRequestByIP.set(ip, now);
+    next();
+  }
+
+  // Health check endpoint
+  app.get('/api/health', (req, res) => {
+    const status = indexManager.getStatus();
+    res.json({
+      status: 'ok',
+      timestamp: new Date().toISOString(),
+      scraper: status,
+      uptime: process.uptime()
+    });
+  });
+
+  // Fault injection applies to API routes but not the health check
+  if (layers.faultInjection && faultConfig) {
+    app.use('/api', faultInjectionMiddleware(faultConfig));
+  }
+
+  // API routes
+  const marketDataLayers = layers.rateLimit ? [minIntervalLimiter] : [];
+  app.use('/api/market-data', ...marketDataLayers, indicesRoutes(indexManager));
+  app.use('/api', timeRoutes());
+  app.use('/api', statusRoutes(indexManager));
+  app.use('/api', localeRoutes());
+  app.use('/api', adminRoutes());
+
+  // Error handling middleware
+  app.use((err, req, res, next) => {
+    const isJsonSyntaxError = err instanceof SyntaxError && 'body' in err;
+    const status = err.status || err.statusCode || (isJsonSyntaxError ? 400 : 500);
+    const isDev = process.env.NODE_ENV === 'development';
+    const payload = {
+      error: status === 400 ? 'Bad request' : 'Internal server error',
+      message: isDev ? redactSecrets(err.message) : (isJsonSyntaxError ? 'Malformed JSON payload' : 'Something went wrong'),
+      request_id: req.id
+    };
+    if (isDev && err.stack) {
+      payload.stack = redactSecrets(err.stack);
+    }
+    // Log the stack rather than the error object, which can carry the raw request body
+    console.error(`[${req?.id || '-'}]`, 'Server error:', redactSecrets(err?.stack || err));
+    if (res.headersSent) return next(err);
+    res.status(status).json(payload);
+  });
+
+  // 404 handler
+  app.use('*', (req, res) => {
+    res.status(404).json({
+      error: 'Not found',
+      message: `Route ${req.originalUrl} not found`,
+      request_id: req.id
+    });
+  });
+
+  return app;
+}
@@ -0,0 +1,77 @@
+import express from 'express';
+import { requireAdmin } from '../utils/adminAuth.js';
+import { redactSecrets } from '../utils/redact.js';
+
+const DEFAULT_PURGE_PATHS = [
+  '/api/market-data/indices/all',
+  '/api/status'
+];
+
+/**
+ * Create admin routes
+ * @returns {Router} Express router
+ */
+export default function createAdminRoutes() {
+  const router = express.Router();
+
+  /**
+   * POST /api/admin/cache/purge
+   * Ask the CDN to invalidate cached API responses
+   * Body: { paths?: string[] } - API paths to purge (default: indices and status)
+   */
+  router.post('/admin/cache/purge', requireAdmin, async (req, res) => {
+    const purgeUrl = process.env.CDN_PURGE_URL;
+    const baseUrl = process.env.PUBLIC_BASE_URL;
+    if (!purgeUrl || !baseUrl) {
+      return res.status(503).json({
+        error: 'CDN purge not configured',
+        message: 'Set CDN_PURGE_URL and PUBLIC_BASE_URL to enable cache purging',
+        request_id: req.id
+      });
+    }
+
+    const paths = req.body?.paths ?? DEFAULT_PURGE_PATHS;
+    if (!Array.isArray(paths) || paths.length === 0 || paths.length > 30 ||
+        !paths.every(path => typeof path === 'string' && path.startsWith('/api/'))) {
+      return res.status(400).json({
+        error: 'Invalid paths',
+        message: 'Field "paths" must be an array of 1-30 paths starting with /api/',
+        request_id: req.id
+      });
+    }
+
+    const files = paths.map(path => baseUrl.replace(/\/+$/, '') + path);
+    try {
+      const response = await fetch(purgeUrl, {
+        method: 'POST',
+        headers: {
+          'Content-Type': 'application/json',
+          ...(process.env.CDN_PURGE_TOKEN ? { 'Authorization': `Bearer ${process.env.CDN_PURGE_TOKEN}` } : {})
+        },
+        body: JSON.stringify({ files }),
+        signal: AbortSignal.timeout(10000)
+      });
+
+      if (!response.ok) {
+        console.error(`❌ CDN purge failed with status ${response.status}`);
+        return res.status(502).json({
+          error: 'CDN purge failed',
+          message: `CDN responded with status ${response.status}`,
+          request_id: req.id
+        });
+      }
+
+      console.log(`🧹 Requested CDN purge of ${files.length} URLs`);
+      res.json({ purged: files });
+    } catch (error) {
+      console.error('❌ CDN purge request failed:', redactSecrets(error.message));
+      res.status(502).json({
+        error: 'CDN purge failed',
+        message: 'Could not reach the CDN purge endpoint',
+        request_id: req.id
+      });
+    }
+  });
+
+  return router;
+}
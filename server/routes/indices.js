@@ -1,6 +1,18 @@
 import express from 'express';
+import { pickFields } from '../utils/fieldSelection.js';
+import { indicesCacheControl } from '../utils/cacheHeaders.js';
+import { parseIndicesQuery, parseFieldsParam, INDEX_FIELDS, SEARCH_RESULT_FIELDS } from '../utils/indicesQuery.js';
+import { COUNTRY_EXCHANGES, isExchangeOpen } from '../utils/marketClock.js';
+import { INDEX_METADATA } from '../scraper/indexMetadata.js';
+import { getDefaultIndices } from '../scraper/defaultIndices.js';
+import { getTimings } from '../utils/timing.js';
 
-const router = express.Router();
+const invalidQuery = (req, res, errors) => res.status(400).json({
+  error: 'Invalid query parameters',
+  message: errors.map(({ param, message }) => `${param}: ${message}`).join('; '),
+  details: errors,
+  request_id: req.id
+});
 
 /**
  * Create indices routes
@@ -8,35 +20,98 @@ const router = express.Router();
  * @returns {Router} Express router
  */
 export default function createIndicesRoutes(indexManager) {
-  
+  const router = express.Router();
+
+  /**
+   * Freshness policy for live data: expired data is refused with 503, stale
+   * data is flagged with X-Data-Status and refetched in the background
+   * @returns {boolean} - Whether a response was sent
+   */
+  const applyFreshness = (req, res) => {
+    if (indexManager.isDataExpired()) {
+      indexManager.refreshIfStale();
+      res.status(503).json({
+        error: 'Live data unavailable',
+        message: 'Market data is too old to be served. A refresh has been requested.',
+        last_updated: indexManager.lastUpdated,
+        request_id: req.id
+      });
+      return true;
+    }
+
+    if (indexManager.isDataStale()) {
+      res.set('X-Data-Status', 'stale');
+      indexManager.refreshIfStale();
+    }
+    res.set('Cache-Control', indicesCacheControl(indexManager));
+    return false;
+  };
+
   /**
    * GET /api/market-data/indices/all
    * Get all available indices with current data
+   * Query params (all optional, see utils/indicesQuery.js):
+   *   symbols - comma-separated index symbols (default: all)
+   *   region - country code or asia | americas | europe (default: all)
+   *   include_closed - include indices whose market is closed (default: true)
+   *   fields - comma-separated field list, e.g. price,percent_change (default: all)
    */
   router.get('/indices/all', (req, res) => {
     try {
-      const data = indexManager.getAllIndices();
-      const status = indexManager.getStatus();
-      
-      // Add appropriate headers for caching
+      // Error responses must not be cached; success overrides this below
       res.set({
-        'Cache-Control': 'no-cache',
+        'Cache-Control': 'no-store',
         'Content-Type': 'application/json'
       });
       
-      // Check if we have any live data
+      const timings = getTimings(req);
+      const { value: query, errors } = timings.measure('validation', () => {
+        const knownSymbols = [
+          ...Object.keys(INDEX_METADATA).filter(symbol => indexManager.isSymbolAllowed(symbol)),
+          ...indexManager.indices.keys()
+        ];
+        return parseIndicesQuery(req.query, knownSymbols);
+      });
+      if (errors.length > 0) {
+        return invalidQuery(req, res, errors);
+      }
+      
+      let data = timings.measure('lookup', () => indexManager.getAllIndices());
+      
+      // Without live data, fall back to the bundled last known values
       if (!data?.metadata || data.metadata.count === 0) {
-        return res.status(503).json({
-          error: 'Live data unavailable',
-          message: 'Unable to fetch market data at this time. Please try again later.',
-          request_id: req.id
-        });
+        indexManager.refreshIfStale();
+        data = timings.measure('fallback', () => getDefaultIndices(indexManager.isSymbolAllowed));
+        if (!data) {
+          return res.status(503).json({
+            error: 'Live data unavailable',
+            message: 'Unable to fetch market data at this time. Please try again later.',
+            request_id: req.id
+          });
+        }
+        res.set('X-Data-Status', 'degraded');
+      } else if (applyFreshness(req, res)) {
+        return;
       }
       
-      // If data is stale, add warning header
-      if (indexManager.isDataStale()) {
-        res.set('X-Data-Status', 'stale');
+      const now = Date.now();
+      const endFilter = timings.start('filter');
+      data.prices = Object.fromEntries(
+        Object.entries(data.prices)
+          .filter(([symbol]) => !query.symbols || query.symbols.has(symbol))
+          .filter(([, index]) => !query.countries || query.countries.has(index.additional_data?.country))
+          .filter(([, index]) => {
+            const exchange = COUNTRY_EXCHANGES[index.additional_data?.country];
+            // Without a known exchange the session is unknown, so it is not reported as open
+            return query.includeClosed || (exchange && isExchangeOpen(exchange, now));
+          })
+          .map(([symbol, index]) => [symbol, pickFields(index, query.fields)])
+      );
+      data.metadata.count = Object.keys(data.prices).length;
+      if (query.symbols) {
+        data.metadata.missing = [...query.symbols].filter(symbol => !data.prices[symbol]);
       }
+      endFilter();
       
       res.json(data);
     } catch (error) {
@@ -52,7 +127,7 @@ export default function createIndicesRoutes(indexManager) {
   /**
    * GET /api/market-data/indices/search
    * Search indices by symbol or name
-   * Query params: q (search query)
+   * Query params: q (search query), fields (optional comma-separated field list)
    */
   router.get('/indices/search', (req, res) => {
     try {
@@ -66,7 +141,17 @@ export default function createIndicesRoutes(indexManager) {
         });
       }
       
-      const results = indexManager.searchIndices(q);
+      const { fields, errors } = parseFieldsParam(req.query.fields, SEARCH_RESULT_FIELDS);
+      if (errors.length > 0) {
+        return invalidQuery(req, res, errors);
+      }
+      
+      if (applyFreshness(req, res)) {
+        return;
+      }
+      
+      const results = getTimings(req).measure('search', () => indexManager.searchIndices(q)
+        .map(result => pickFields(result, fields, ['symbol'])));
       
       res.json({
         query: q,
@@ -86,6 +171,7 @@ export default function createIndicesRoutes(indexManager) {
   /**
    * GET /api/market-data/indices/:symbol
    * Get specific index data by symbol
+   * Query params: fields (optional comma-separated field list)
    */
   router.get('/indices/:symbol', (req, res) => {
     try {
@@ -99,7 +185,28 @@ export default function createIndicesRoutes(indexManager) {
         });
       }
       
-      const indexData = indexManager.getIndex(symbol);
+      const { fields, errors } = parseFieldsParam(req.query.fields, INDEX_FIELDS);
+      if (errors.length > 0) {
+        return invalidQuery(req, res, errors);
+      }
+      
+      let indexData = getTimings(req).measure('lookup', () => indexManager.getIndex(symbol));
+      indexManager.refreshIfStale();
+      
+      // Before the first scrape, serve the bundled last known value
+      const degraded = !indexData && indexManager.indices.size === 0;
+      if (degraded) {
+        const defaults = getDefaultIndices(indexManager.isSymbolAllowed);
+        if (!defaults) {
+          res.set('Cache-Control', 'no-store');
+          return res.status(503).json({
+            error: 'Live data unavailable',
+            message: 'Unable to fetch market data at this time. Please try again later.',
+            request_id: req.id
+          });
+        }
+        indexData = defaults.prices[symbol] || null;
+      }
       
       if (!indexData) {
         return res.status(404).json({
@@ -109,10 +216,23 @@ export default function createIndicesRoutes(indexManager) {
         });
       }
       
-      res.json({
+      if (degraded) {
+        res.set({ 'Cache-Control': 'no-store', 'X-Data-Status': 'degraded' });
+        return res.json(pickFields({
+          symbol,
+          ...indexData,
+          degraded: true
+        }, fields, ['symbol', 'degraded']));
+      }
+      
+      if (applyFreshness(req, res)) {
+        return;
+      }
+      
+      res.json(pickFields({
         symbol,
         ...indexData
-      });
+      }, fields, ['symbol']));
     } catch (error) {
       console.error('Error in /indices/:symbol:', error);
       res.status(500).json({
@@ -0,0 +1,47 @@
+import express from 'express';
+import { negotiateLocale, getLocaleHints } from '../utils/localeHints.js';
+
+/**
+ * Create localization routes
+ * @returns {Router} Express router
+ */
+export default function createLocaleRoutes() {
+  const router = express.Router();
+
+  /**
+   * GET /api/locale
+   * Number, currency and date formatting hints plus localized country, index
+   * and exchange names, negotiated from the Accept-Language header
+   * Query params: locale (optional override), currency (optional ISO 4217 code)
+   */
+  router.get('/locale', (req, res) => {
+    try {
+      const { currency } = req.query;
+      if (currency !== undefined && !/^[A-Za-z]{3}$/.test(currency)) {
+        return res.status(400).json({
+          error: 'Invalid currency',
+          message: 'Query parameter "currency" must be a 3-letter ISO 4217 code',
+          request_id: req.id
+        });
+      }
+
+      const locale = negotiateLocale(typeof req.query.locale === 'string' ? req.query.locale : req.get('accept-language'));
+      res.set({
+        'Cache-Control': 'public, max-age=86400',
+        'Content-Language': locale
+      });
+      // Append, so the Vary: Origin set by the cors layer is kept
+      res.vary('Accept-Language');
+      res.json(getLocaleHints(locale, currency));
+    } catch (error) {
+      console.error('Error in /locale:', error);
+      res.status(500).json({
+        error: 'Failed to build locale hints',
+        message: error.message,
+        request_id: req.id
+      });
+    }
+  });
+
+  return router;
+}
@@ -0,0 +1,141 @@
+import express from 'express';
+import { requireAdmin } from '../utils/adminAuth.js';
+
+const SEVERITIES = ['minor', 'major', 'critical'];
+const MAX_INCIDENTS = 20;
+
+/**
+ * Create public status routes
+ * @param {IndexManager} indexManager - Index manager instance
+ * @returns {Router} Express router
+ */
+export default function createStatusRoutes(indexManager) {
+  const router = express.Router();
+  const cacheMs = Number(process.env.STATUS_CACHE_MS || 30000);
+  const incidents = []; // newest first
+  let cached = null;
+
+  const buildStatus = () => {
+    const scraper = indexManager.getStatus();
+    const stale = indexManager.isDataStale();
+    const openIncidents = incidents.filter(incident => !incident.resolvedAt);
+
+    let scraperStatus = 'operational';
+    if (scraper.status === 'error') scraperStatus = 'down';
+    else if (scraper.status !== 'success' || stale) scraperStatus = 'degraded';
+
+    let overall = scraperStatus;
+    if (openIncidents.some(incident => incident.severity === 'critical')) overall = 'down';
+    else if (overall === 'operational' && openIncidents.some(incident => incident.severity === 'major')) overall = 'degraded';
+
+    return {
+      status: overall,
+      updatedAt: new Date().toISOString(),
+      components: {
+        api: { status: 'operational' },
+        scraper: {
+          status: scraperStatus,
+          successRate: scraper.successRate
+        }
+      },
+      freshness: {
+        indices: {
+          lastUpdated: scraper.lastSuccessfulScrape,
+          ageMs: scraper.timeSinceLastSuccess,
+          maxAgeMs: indexManager.maxAgeMs,
+          stale
+        }
+      },
+      incidents
+    };
+  };
+
+  /**
+   * GET /api/status
+   * Public component health, data freshness and recent incidents, cached for STATUS_CACHE_MS
+   */
+  router.get('/status', (req, res) => {
+    try {
+      const now = Date.now();
+      if (!cached || now - cached.at > cacheMs) {
+        cached = { at: now, body: buildStatus() };
+      }
+
+      res.set('Cache-Control', `public, max-age=${Math.floor(cacheMs / 1000)}`);
+      res.json(cached.body);
+    } catch (error) {
+      console.error('Error in /status:', error);
+      res.status(500).json({
+        error: 'Failed to build status',
+        message: error.message,
+        request_id: req.id
+      });
+    }
+  });
+
+  /**
+   * POST /api/admin/incidents
+   * Annotate the status page with an incident
+   * Body: { title, message?, severity: minor | major | critical }
+   */
+  router.post('/admin/incidents', requireAdmin, (req, res) => {
+    const { title, message = '', severity = 'minor' } = req.body || {};
+
+    if (typeof title !== 'string' || !title.trim() || title.length > 200) {
+      return res.status(400).json({
+        error: 'Invalid incident',
+        message: 'Field "title" is required and must be at most 200 characters',
+        request_id: req.id
+      });
+    }
+    if (typeof message !== 'string' || message.length > 2000) {
+      return res.status(400).json({
+        error: 'Invalid incident',
+        message: 'Field "message" must be a string of at most 2000 characters',
+        request_id: req.id
+      });
+    }
+    if (!SEVERITIES.includes(severity)) {
+      return res.status(400).json({
+        error: 'Invalid incident',
+        message: `Field "severity" must be one of: ${SEVERITIES.join(', ')}`,
+        request_id: req.id
+      });
+    }
+
+    const incident = {
+      id: Date.now().toString(36) + Math.random().toString(36).slice(2, 6),
+      title: title.trim(),
+      message,
+      severity,
+      createdAt: new Date().toISOString(),
+      resolvedAt: null
+    };
+    incidents.unshift(incident);
+    incidents.splice(MAX_INCIDENTS);
+    cached = null;
+
+    res.status(201).json(incident);
+  });
+
+  /**
+   * DELETE /api/admin/incidents/:id
+   * Mark an incident as resolved; it stays listed until it ages out
+   */
+  router.delete('/admin/incidents/:id', requireAdmin, (req, res) => {
+    const incident = incidents.find(item => item.id === req.params.id);
+    if (!incident) {
+      return res.status(404).json({
+        error: 'Incident not found',
+        message: `Incident "${req.params.id}" not found`,
+        request_id: req.id
+      });
+    }
+
+    incident.resolvedAt = incident.resolvedAt || new Date().toISOString();
+    cached = null;
+    res.json(incident);
+  });
+
+  return router;
+}
@@ -0,0 +1,49 @@
+import express from 'express';
+import { EXCHANGE_HOURS, getExchangeClock } from '../utils/marketClock.js';
+
+/**
+ * Create server time routes
+ * @returns {Router} Express router
+ */
+export default function createTimeRoutes() {
+  const router = express.Router();
+
+  /**
+   * GET /api/time
+   * Server UTC time plus local time and open/close countdowns per exchange
+   * Query params: exchanges (optional comma-separated list, e.g. NSE,NYSE)
+   */
+  router.get('/time', (req, res) => {
+    try {
+      const requested = typeof req.query.exchanges === 'string' && req.query.exchanges.trim()
+        ? req.query.exchanges.split(',').map(id => id.trim().toUpperCase()).filter(Boolean)
+        : Object.keys(EXCHANGE_HOURS);
+
+      const unknown = requested.filter(id => !EXCHANGE_HOURS[id]);
+      if (unknown.length > 0) {
+        return res.status(400).json({
+          error: 'Unknown exchange',
+          message: `Unsupported exchanges: ${unknown.join(', ')}. Supported: ${Object.keys(EXCHANGE_HOURS).join(', ')}`,
+          request_id: req.id
+        });
+      }
+
+      const now = Date.now();
+      res.set('Cache-Control', 'no-store');
+      res.json({
+        utc: new Date(now).toISOString(),
+        timestamp: now,
+        exchanges: Object.fromEntries(requested.map(id => [id, getExchangeClock(id, now)]))
+      });
+    } catch (error) {
+      console.error('Error in /time:', error);
+      res.status(500).json({
+        error: 'Failed to compute market clock',
+        message: error.message,
+        request_id: req.id
+      });
+    }
+  });
+
+  return router;
+}
@@ -0,0 +1,79 @@
+import { readFileSync } from 'fs';
+import { getIndexMetadata } from './indexMetadata.js';
+
+/**
+ * Last known index levels shipped with the server (data/defaultIndices.json).
+ * Served with degraded: true when no live data has been scraped yet, so the
+ * frontend can render something instead of an error. The file is written from
+ * a real scrape by `npm run snapshot:defaults`; until one is committed the
+ * indices routes return 503 without live data. Files marked "synthetic" are
+ * never served.
+ */
+const fixturePath = new URL('../data/defaultIndices.json', import.meta.url);
+
+let fixture = null;
+try {
+  fixture = JSON.parse(readFileSync(fixturePath, 'utf8'));
+} catch (error) {
+  if (error.code === 'ENOENT') {
+    console.log('ℹ️ No bundled default indices (create them with npm run snapshot:defaults)');
+  } else {
+    console.error('❌ Failed to load default indices fixture:', error.message);
+  }
+}
+
+/**
+ * Turn a snapshot file into the shape of IndexManager.getAllIndices()
+ * @param {Object|null} snapshot - Parsed data/defaultIndices.json
+ * @param {Function} isSymbolAllowed - Allow/deny predicate of the index manager
+ * @returns {Object|null} - {prices, metadata}, or null for missing, empty or synthetic snapshots
+ */
+export function buildDefaultIndices(snapshot, isSymbolAllowed = () => true) {
+  if (!snapshot?.indices?.length || snapshot.synthetic) return null;
+
+  const capturedAt = Date.parse(snapshot.capturedAt) || null;
+  const prices = Object.fromEntries(
+    snapshot.indices
+      .filter(index => isSymbolAllowed(index.symbol, index.exchange))
+      .map(index => [index.symbol, {
+        price: index.price,
+        change: index.change ?? null,
+        percent_change: index.percent_change ?? null,
+        additional_data: {
+          name: index.name || index.symbol,
+          exchange: index.exchange || 'Unknown',
+          ...getIndexMetadata(index.symbol),
+          lastUpdated: capturedAt
+        }
+      }])
+  );
+
+  return {
+    prices,
+    metadata: {
+      lastUpdated: capturedAt,
+      lastSuccessfulScrape: null,
+      status: 'fallback',
+      count: Object.keys(prices).length,
+      degraded: true
+    }
+  };
+}
+
+/**
+ * Whether the bundled fallback may be served (SERVE_DEFAULT_INDICES, default: true)
+ * @returns {boolean}
+ */
+export function defaultIndicesEnabled() {
+  return process.env.SERVE_DEFAULT_INDICES !== 'false' && buildDefaultIndices(fixture) !== null;
+}
+
+/**
+ * Bundled indices in the same shape as IndexManager.getAllIndices()
+ * @param {Function} isSymbolAllowed - Allow/deny predicate of the index manager
+ * @returns {Object|null} - {prices, metadata}, or null when disabled or unavailable
+ */
+export function getDefaultIndices(isSymbolAllowed = () => true) {
+  if (process.env.SERVE_DEFAULT_INDICES === 'false') return null;
+  return buildDefaultIndices(fixture, isSymbolAllowed);
+}
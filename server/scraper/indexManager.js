@@ -1,3 +1,7 @@
+import { getIndexMetadata } from './indexMetadata.js';
+import { createSymbolFilter } from '../utils/symbolFilter.js';
+import { COUNTRY_EXCHANGES } from '../utils/marketClock.js';
+
 export class IndexManager {
   constructor() {
     this.indices = new Map(); // Map<symbol, indexData>
@@ -6,6 +10,60 @@ export class IndexManager {
     this.scrapeStatus = 'initializing'; // 'initializing' | 'success' | 'stale' | 'error'
     this.totalScrapes = 0;
     this.failedScrapes = 0;
+    this.maxAgeMs = Number(process.env.INDICES_MAX_AGE_MS || 5 * 60 * 1000);
+    this.maxServeAgeMs = Number(process.env.INDICES_MAX_SERVE_AGE_MS || 0); // 0 = never reject
+    this.staleHandler = null;
+    this.pendingRefresh = null;
+    this.maxJumpPercent = Number(process.env.INDICES_MAX_JUMP_PERCENT || 20);
+    this.anomalyConfirmations = Number(process.env.INDICES_ANOMALY_CONFIRMATIONS || 3);
+    this.anomalyStreaks = new Map(); // Map<symbol, {price, streak}> - last rejected level and how often it was seen in a row
+    this.isSymbolAllowed = createSymbolFilter({
+      allow: process.env.INDICES_ALLOWLIST,
+      deny: process.env.INDICES_DENYLIST
+    });
+    this.exchangeSessions = new Map(); // Map<exchangeId, {session, closedAt}> - closed once that exchange's end-of-day snapshot is captured
+  }
+
+  /**
+   * Overall session: closed only when every tracked exchange holds its end-of-day snapshot
+   * @returns {'open'|'closed'}
+   */
+  get session() {
+    const sessions = [...this.exchangeSessions.values()];
+    return sessions.length > 0 && sessions.every(({ session }) => session === 'closed') ? 'closed' : 'open';
+  }
+
+  /**
+   * When the last tracked exchange closed, or null while any is open
+   * @returns {number|null}
+   */
+  get sessionClosedAt() {
+    if (this.session !== 'closed') return null;
+    return Math.max(...[...this.exchangeSessions.values()].map(({ closedAt }) => closedAt));
+  }
+
+  /**
+   * Session of the exchange an index trades on (via COUNTRY_EXCHANGES).
+   * Indices on untracked exchanges follow the overall session.
+   * @param {Object} data - Stored index data
+   * @returns {'open'|'closed'}
+   */
+  getIndexSession(data) {
+    const exchange = COUNTRY_EXCHANGES[data?.additional_data?.country];
+    return this.exchangeSessions.get(exchange)?.session || this.session;
+  }
+
+  /**
+   * Stored index data annotated with its exchange session
+   */
+  withSession(data) {
+    return {
+      ...data,
+      additional_data: {
+        ...data.additional_data,
+        session: this.getIndexSession(data)
+      }
+    };
   }
 
   /**
@@ -18,10 +76,37 @@ export class IndexManager {
     }
 
     const now = Date.now();
+    const previous = new Map(this.indices);
     this.indices.clear();
 
+    // Keep the end-of-day snapshot of exchanges that have closed, whether or
+    // not this scrape still lists their indices
+    previous.forEach((data, symbol) => {
+      if (this.getIndexSession(data) === 'closed') {
+        this.indices.set(symbol, data);
+      }
+    });
+    const frozen = this.indices.size;
+
+    let excluded = 0;
     indicesData.forEach(index => {
+      if (index.symbol && !this.isSymbolAllowed(index.symbol, index.exchange)) {
+        excluded++;
+        return;
+      }
+
+      const previousData = previous.get(index.symbol);
+      if (previousData && this.getIndexSession(previousData) === 'closed') {
+        return;
+      }
+
       if (index.symbol && typeof index.price === 'number') {
+        const held = this.checkAnomaly(index, previousData, now);
+        if (held) {
+          this.indices.set(index.symbol, held);
+          return;
+        }
+
         this.indices.set(index.symbol, {
           price: index.price,
           change: index.change || 0,
@@ -29,6 +114,7 @@ export class IndexManager {
           additional_data: {
             name: index.name || index.symbol,
             exchange: index.exchange || 'Unknown',
+            ...getIndexMetadata(index.symbol),
             lastUpdated: now
           }
         });
@@ -40,7 +126,61 @@ export class IndexManager {
     this.scrapeStatus = 'success';
     this.totalScrapes++;
     
-    console.log(`✅ Updated ${this.indices.size} indices in memory`);
+    console.log(`✅ Updated ${this.indices.size} indices in memory` +
+      `${frozen ? ` (${frozen} held at end-of-day snapshot)` : ''}` +
+      `${excluded ? ` (${excluded} excluded by allow/deny lists)` : ''}`);
+  }
+
+  /**
+   * Compare an incoming value with the previous snapshot. Jumps beyond
+   * INDICES_MAX_JUMP_PERCENT are most likely parsing errors, so the previous
+   * value is kept with a warning until the new level has been seen
+   * INDICES_ANOMALY_CONFIRMATIONS times in a row, each reading within
+   * INDICES_MAX_JUMP_PERCENT of the one before.
+   * @param {Object} index - Incoming index from scraper
+   * @param {Object|undefined} previousData - Stored data from the previous update
+   * @param {number} now - Update timestamp
+   * @returns {Object|null} - Previous data annotated with the anomaly, or null to accept the new value
+   */
+  checkAnomaly(index, previousData, now) {
+    const previousPrice = previousData?.price;
+    if (!previousPrice || this.maxJumpPercent <= 0) {
+      this.anomalyStreaks.delete(index.symbol);
+      return null;
+    }
+
+    const jumpPercent = Math.abs(index.price - previousPrice) / previousPrice * 100;
+    if (jumpPercent <= this.maxJumpPercent) {
+      this.anomalyStreaks.delete(index.symbol);
+      return null;
+    }
+
+    // Only readings that agree with the last rejected one confirm a new level
+    const pending = this.anomalyStreaks.get(index.symbol);
+    const sameLevel = pending &&
+      Math.abs(index.price - pending.price) / pending.price * 100 <= this.maxJumpPercent;
+    const streak = sameLevel ? pending.streak + 1 : 1;
+    if (streak >= this.anomalyConfirmations) {
+      console.warn(`⚠️  Accepting ${index.symbol} at ${index.price} after ${streak} consecutive readings`);
+      this.anomalyStreaks.delete(index.symbol);
+      return null;
+    }
+
+    this.anomalyStreaks.set(index.symbol, { price: index.price, streak });
+    console.warn(`⚠️  Suspicious jump for ${index.symbol}: ${previousPrice} -> ${index.price} (${jumpPercent.toFixed(2)}%), keeping previous value`);
+
+    return {
+      ...previousData,
+      additional_data: {
+        ...previousData.additional_data,
+        anomaly: {
+          rejectedPrice: index.price,
+          previousPrice,
+          jumpPercent: Number(jumpPercent.toFixed(2)),
+          detectedAt: now
+        }
+      }
+    };
   }
 
   /**
@@ -49,12 +189,18 @@ export class IndexManager {
    */
   getAllIndices() {
     return {
-      prices: Object.fromEntries(this.indices),
+      prices: Object.fromEntries([...this.indices].map(([symbol, data]) => [symbol, this.withSession(data)])),
       metadata: {
         lastUpdated: this.lastUpdated,
         lastSuccessfulScrape: this.lastSuccessfulScrape,
         status: this.scrapeStatus,
-        count: this.indices.size
+        count: this.indices.size,
+        session: this.session,
+        stale: this.isDataStale(),
+        maxAgeMs: this.maxAgeMs,
+        anomalies: [...this.indices]
+          .filter(([, data]) => data.additional_data?.anomaly)
+          .map(([symbol]) => symbol)
       }
     };
   }
@@ -83,6 +229,8 @@ export class IndexManager {
           symbol,
           name,
           exchange,
+          country: data.additional_data?.country ?? null,
+          currency: data.additional_data?.currency ?? null,
           price: data.price,
           change: data.change,
           percent_change: data.percent_change
@@ -99,7 +247,8 @@ export class IndexManager {
    * @returns {Object|null} - Index data or null if not found
    */
   getIndex(symbol) {
-    return this.indices.get(symbol) || null;
+    const data = this.indices.get(symbol);
+    return data ? this.withSession(data) : null;
   }
 
   /**
@@ -123,6 +272,9 @@ export class IndexManager {
 
     return {
       status: this.scrapeStatus,
+      session: this.session,
+      sessionClosedAt: this.sessionClosedAt,
+      exchangeSessions: Object.fromEntries(this.exchangeSessions),
       indicesCount: this.indices.size,
       lastUpdated: this.lastUpdated,
       lastSuccessfulScrape: this.lastSuccessfulScrape,
@@ -137,12 +289,66 @@ export class IndexManager {
   }
 
   /**
-   * Check if data is stale (older than 5 minutes)
+   * Check if data is stale (older than INDICES_MAX_AGE_MS, default 5 minutes)
    * @returns {boolean}
    */
   isDataStale() {
     if (!this.lastSuccessfulScrape) return true;
-    return Date.now() - this.lastSuccessfulScrape > 5 * 60 * 1000; // 5 minutes
+    if (this.session === 'closed') return false; // End-of-day snapshot stays valid until the next open
+    return Date.now() - this.lastSuccessfulScrape > this.maxAgeMs;
+  }
+
+  /**
+   * Check if data is too old to be served at all (INDICES_MAX_SERVE_AGE_MS)
+   * @returns {boolean}
+   */
+  isDataExpired() {
+    if (!this.maxServeAgeMs || !this.lastSuccessfulScrape || this.session === 'closed') return false;
+    return Date.now() - this.lastSuccessfulScrape > this.maxServeAgeMs;
+  }
+
+  /**
+   * Record an exchange's session. Switching to closed after a successful
+   * scrape marks that scrape as the exchange's end-of-day snapshot; its
+   * indices keep those values until it is switched back to open.
+   * @param {string} exchangeId - Key of EXCHANGE_HOURS
+   * @param {'open'|'closed'} session - Session state
+   */
+  setExchangeSession(exchangeId, session) {
+    const current = this.exchangeSessions.get(exchangeId);
+    if (current?.session === session) return;
+    this.exchangeSessions.set(exchangeId, { session, closedAt: session === 'closed' ? Date.now() : null });
+    if (session === 'closed') {
+      console.log(`🌙 ${exchangeId} closed, serving end-of-day snapshot`);
+    } else if (current) {
+      console.log(`🌅 ${exchangeId} open, resuming live updates`);
+    }
+  }
+
+  /**
+   * Register a callback that refetches data when it goes stale
+   * @param {Function} handler - Async function performing the refetch
+   */
+  setStaleHandler(handler) {
+    this.staleHandler = handler;
+  }
+
+  /**
+   * Kick off a background refetch if data is stale. Concurrent calls share one refresh.
+   * @returns {boolean} - Whether a refresh is running
+   */
+  refreshIfStale() {
+    if (!this.staleHandler || !this.isDataStale()) return false;
+    if (this.pendingRefresh) return true;
+
+    console.log('⏰ Indices data is stale, triggering refetch...');
+    this.pendingRefresh = Promise.resolve()
+      .then(() => this.staleHandler())
+      .catch(error => console.error('❌ Stale data refetch failed:', error.message))
+      .finally(() => {
+        this.pendingRefresh = null;
+      });
+    return true;
   }
 
   /**
@@ -153,6 +359,8 @@ export class IndexManager {
     this.lastUpdated = null;
     this.lastSuccessfulScrape = null;
     this.scrapeStatus = 'initializing';
+    this.anomalyStreaks.clear();
+    this.exchangeSessions.clear();
     console.log('🧹 Cleared all indices data');
   }
 }
@@ -0,0 +1,141 @@
+/**
+ * Static metadata for the indices we scrape, keyed by internal symbol.
+ * constituentCount is null for indices whose membership size floats
+ * (composites) or that have no constituents (volatility indices).
+ */
+export const INDEX_METADATA = {
+  'NIFTY': {
+    country: 'IN',
+    currency: 'INR',
+    description: 'NSE benchmark of 50 large-cap Indian companies',
+    constituentCount: 50
+  },
+  'SENSEX': {
+    country: 'IN',
+    currency: 'INR',
+    description: 'BSE benchmark of 30 large, established Indian companies',
+    constituentCount: 30
+  },
+  'BANKNIFTY': {
+    country: 'IN',
+    currency: 'INR',
+    description: 'Most liquid large-cap Indian banking stocks listed on NSE',
+    constituentCount: 12
+  },
+  'INDIAVIX': {
+    country: 'IN',
+    currency: null,
+    description: 'Expected 30-day volatility implied by NIFTY options',
+    constituentCount: null
+  },
+  'DJI': {
+    country: 'US',
+    currency: 'USD',
+    description: 'Price-weighted index of 30 US blue-chip companies',
+    constituentCount: 30
+  },
+  'S&P-500': {
+    country: 'US',
+    currency: 'USD',
+    description: 'Market-cap weighted index of 500 leading US companies',
+    constituentCount: 500
+  },
+  'IXIC': {
+    country: 'US',
+    currency: 'USD',
+    description: 'Nasdaq Composite of all common stocks listed on the Nasdaq exchange',
+    constituentCount: null
+  },
+  'RUSSELL2000': {
+    country: 'US',
+    currency: 'USD',
+    description: 'Small-cap segment of the US equity market',
+    constituentCount: 2000
+  },
+  'VIX': {
+    country: 'US',
+    currency: null,
+    description: 'Expected 30-day volatility implied by S&P 500 options',
+    constituentCount: null
+  },
+  'TSX': {
+    country: 'CA',
+    currency: 'CAD',
+    description: 'S&P/TSX Composite of the largest companies on the Toronto Stock Exchange',
+    constituentCount: null
+  },
+  'BOVESPA': {
+    country: 'BR',
+    currency: 'BRL',
+    description: 'Benchmark of the most traded stocks on B3 in São Paulo',
+    constituentCount: null
+  },
+  'BMVIPC': {
+    country: 'MX',
+    currency: 'MXN',
+    description: 'Benchmark of the largest and most liquid stocks on the Mexican Stock Exchange',
+    constituentCount: 35
+  },
+  'DAX': {
+    country: 'DE',
+    currency: 'EUR',
+    description: 'Blue-chip index of the 40 largest companies on the Frankfurt Stock Exchange',
+    constituentCount: 40
+  },
+  'UKX': {
+    country: 'GB',
+    currency: 'GBP',
+    description: 'FTSE 100 index of the largest companies listed on the London Stock Exchange',
+    constituentCount: 100
+  },
+  'CAC-40': {
+    country: 'FR',
+    currency: 'EUR',
+    description: 'Benchmark of 40 large companies listed on Euronext Paris',
+    constituentCount: 40
+  },
+  'EUROSTOXX50': {
+    country: 'EU',
+    currency: 'EUR',
+    description: 'Blue-chip index of 50 eurozone sector leaders',
+    constituentCount: 50
+  },
+  'AEX': {
+    country: 'NL',
+    currency: 'EUR',
+    description: 'Benchmark of the most traded companies on Euronext Amsterdam',
+    constituentCount: null
+  },
+  'IBEX35': {
+    country: 'ES',
+    currency: 'EUR',
+    description: 'Benchmark of the 35 most liquid stocks on the Spanish stock market',
+    constituentCount: 35
+  },
+  'FTSEMIB': {
+    country: 'IT',
+    currency: 'EUR',
+    description: 'Benchmark of the 40 most traded stocks on Borsa Italiana',
+    constituentCount: 40
+  },
+  'SMI': {
+    country: 'CH',
+    currency: 'CHF',
+    description: 'Swiss Market Index of the 20 largest SIX Swiss Exchange companies',
+    constituentCount: 20
+  }
+};
+
+/**
+ * Get metadata for an index symbol, with null fields for unknown symbols
+ * @param {string} symbol - Index symbol
+ * @returns {Object} - {country, currency, description, constituentCount}
+ */
+export function getIndexMetadata(symbol) {
+  return INDEX_METADATA[symbol] || {
+    country: null,
+    currency: null,
+    description: null,
+    constituentCount: null
+  };
+}
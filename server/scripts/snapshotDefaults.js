@@ -0,0 +1,37 @@
+import { mkdirSync, writeFileSync } from 'fs';
+
+/**
+ * Refresh data/defaultIndices.json from a running server's live data.
+ * Usage: npm run snapshot:defaults [-- http://localhost:3001]
+ */
+const baseUrl = (process.argv[2] || process.env.API_URL || 'http://localhost:3001').replace(/\/+$/, '');
+const outputPath = new URL('../data/defaultIndices.json', import.meta.url);
+
+const response = await fetch(`${baseUrl}/api/market-data/indices/all`);
+if (!response.ok) {
+  console.error(`❌ ${baseUrl} responded with status ${response.status}`);
+  process.exit(1);
+}
+
+const data = await response.json();
+if (data.metadata?.degraded || !data.metadata?.lastSuccessfulScrape) {
+  console.error('❌ Server is not serving live data; refusing to snapshot a fallback payload');
+  process.exit(1);
+}
+
+const indices = Object.entries(data.prices).map(([symbol, index]) => ({
+  symbol,
+  name: index.additional_data?.name || symbol,
+  exchange: index.additional_data?.exchange || 'Unknown',
+  price: index.price,
+  change: index.change,
+  percent_change: index.percent_change
+}));
+
+mkdirSync(new URL('.', outputPath), { recursive: true });
+writeFileSync(outputPath, JSON.stringify({
+  capturedAt: new Date(data.metadata.lastSuccessfulScrape).toISOString(),
+  indices
+}, null, 2) + '\n');
+
+console.log(`✅ Wrote ${indices.length} indices to data/defaultIndices.json`);
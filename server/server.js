@@ -1,115 +1,60 @@
-import express from 'express';
-import cors from 'cors';
 import { IndexManager } from './scraper/indexManager.js';
 import { IndexScraper } from './scraper/indexScraper.js';
-import indicesRoutes from './routes/indices.js';
+import { buildApp, parseDisabledLayers } from './app.js';
+import { getFaultInjectionConfig, wrapScraperWithFaults } from './utils/faultInjection.js';
+import { getExchangeSessions, parseExchangeList } from './utils/marketClock.js';
 
-const app = express();
 const PORT = process.env.PORT || 3001;
 const DISABLE_SCRAPING = process.env.DISABLE_SCRAPING === 'true';
 const MIN_API_INTERVAL_MS = Number(process.env.MIN_API_INTERVAL_MS || 1000);
-
-// CORS configuration for Chrome extension
-const corsOptions = {
-  origin: true, // Temporarily allow all origins for testing
-  methods: ['GET', 'POST', 'PUT', 'DELETE'],
-  allowedHeaders: ['Content-Type', 'Authorization'],
-  credentials: true
-};
-
-// Middleware
-app.use(cors(corsOptions));
-app.use((req, res, next) => {
-  const rid = Date.now().toString(36) + Math.random().toString(36).slice(2, 10);
-  req.id = rid;
-  res.setHeader('X-Request-Id', rid);
-  next();
-});
-
-app.use(express.json({ limit: '1mb' }));
-
-app.use((req, res, next) => {
-  res.setTimeout(15000, () => {
-    if (!res.headersSent) {
-      res.status(503).json({
-        error: 'Request timeout',
-        message: 'The server took too long to respond',
-        request_id: req.id
-      });
-    }
-  });
-  next();
-});
-
-const lastRequestByIP = new Map();
-function minIntervalLimiter(req, res, next) {
-  const ip = req.ip || req.connection?.remoteAddress || 'unknown';
-  const now = Date.now();
-  const last = lastRequestByIP.get(ip) || 0;
-  const elapsed = now - last;
-  if (elapsed < MIN_API_INTERVAL_MS) {
-    const wait = MIN_API_INTERVAL_MS - elapsed;
-    setTimeout(() => {
-      lastRequestByIP.set(ip, Date.now());
-      next();
-    }, wait);
-    return;
-  }
-  lastRequestByIP.set(ip, now);
-  next();
-}
+const OFF_HOURS_SNAPSHOT = process.env.OFF_HOURS_SNAPSHOT !== 'false';
+const OFF_HOURS_GRACE_MS = Number(process.env.OFF_HOURS_GRACE_MS || 10 * 60 * 1000);
+const OFF_HOURS_EXCHANGES = parseExchangeList(process.env.OFF_HOURS_EXCHANGES);
 
 // Initialize index manager and scraper
 const indexManager = new IndexManager();
 const indexScraper = new IndexScraper();
 
-    // Health check endpoint
-app.get('/api/health', (req, res) => {
-  const status = indexManager.getStatus();
-  res.json({
-    status: 'ok',
-    timestamp: new Date().toISOString(),
-    scraper: status,
-    uptime: process.uptime()
-  });
-});
+const faultConfig = getFaultInjectionConfig();
+if (faultConfig) {
+  console.warn('⚠️ Fault injection enabled:', faultConfig);
+  wrapScraperWithFaults(indexScraper, faultConfig);
+}
 
-// API routes
-app.use('/api/market-data', minIntervalLimiter, indicesRoutes(indexManager));
-
-// Error handling middleware
-app.use((err, req, res, next) => {
-  const isJsonSyntaxError = err instanceof SyntaxError && 'body' in err;
-  const status = err.status || err.statusCode || (isJsonSyntaxError ? 400 : 500);
-  const isDev = process.env.NODE_ENV === 'development';
-  const payload = {
-    error: status === 400 ? 'Bad request' : 'Internal server error',
-    message: isDev ? err.message : (isJsonSyntaxError ? 'Malformed JSON payload' : 'Something went wrong'),
-    request_id: req.id
-  };
-  if (isDev && err.stack) {
-    payload.stack = err.stack;
+const app = buildApp({
+  layers: parseDisabledLayers(process.env.DISABLED_LAYERS),
+  minApiIntervalMs: MIN_API_INTERVAL_MS,
+  faultConfig
+}, { indexManager });
+
+/**
+ * Scrape while any tracked exchange is trading. Each exchange's indices are
+ * held at their end-of-day snapshot once it closes, and scraping stops when
+ * every tracked exchange holds one.
+ */
+async function scrapeForSession() {
+  const sessions = Object.entries(OFF_HOURS_SNAPSHOT ? getExchangeSessions(OFF_HOURS_EXCHANGES, OFF_HOURS_GRACE_MS) : {});
+
+  // Reopened exchanges take live values from this scrape on
+  sessions.filter(([, session]) => session === 'open').forEach(([id]) => indexManager.setExchangeSession(id, 'open'));
+  if (indexManager.session === 'closed') {
+    return;
   }
-  console.error(`[${req?.id || '-'}]`, 'Server error:', err);
-  if (res.headersSent) return next(err);
-  res.status(status).json(payload);
-});
 
-// 404 handler
-app.use('*', (req, res) => {
-  res.status(404).json({
-    error: 'Not found',
-    message: `Route ${req.originalUrl} not found`,
-    request_id: req.id
-  });
-});
+  const previousScrape = indexManager.lastSuccessfulScrape;
+  await indexScraper.scrapeIndices(indexManager);
+  if (indexManager.lastSuccessfulScrape !== previousScrape) {
+    // This scrape is the end-of-day snapshot of exchanges that have closed
+    sessions.filter(([, session]) => session === 'closed').forEach(([id]) => indexManager.setExchangeSession(id, 'closed'));
+  }
+}
 
 // Initialize scraper and start periodic updates
 async function initializeServer() {
   try {
     console.log('🚀 Starting Market Pulse Backend Server...');
     
-    // Initialize scraper - no seed data fallback
+    // Initialize scraper - until the first scrape, indices routes serve the bundled fallback
     if (DISABLE_SCRAPING) {
       console.log('📊 Scraping disabled');
       return;
@@ -122,14 +67,17 @@ async function initializeServer() {
       
       // Start initial scrape
       console.log('📊 Starting initial data scrape...');
-      await indexScraper.scrapeIndices(indexManager);
+      await scrapeForSession();
+      
+      // Refetch on demand when requests find the data stale
+      indexManager.setStaleHandler(scrapeForSession);
       
       // Schedule periodic scraping
       const scrapeInterval = Number(process.env.SCRAPE_INTERVAL_MS || 15000);
       setInterval(async () => {
         try {
           console.log('🔄 Scheduled scrape running...');
-          await indexScraper.scrapeIndices(indexManager);
+          await scrapeForSession();
         } catch (error) {
           console.error('❌ Scheduled scrape failed:', error);
         }
@@ -137,7 +85,7 @@ async function initializeServer() {
       
     } catch (scraperError) {
       console.error('❌ Scraper initialization failed:', scraperError.message);
-      // Server will continue but API endpoints will return 503 when data is requested
+      // Server will continue; indices routes serve the bundled fallback (or 503 if none is bundled)
     }
     
     console.log('✅ Server initialized successfully');
@@ -0,0 +1,110 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { once } from 'events';
+import { buildApp } from '../app.js';
+import { IndexManager } from '../scraper/indexManager.js';
+import { requireAdmin, isAdminRequest } from '../utils/adminAuth.js';
+
+const ADMIN_KEY = 'test-admin-key';
+
+function withAdminKey(t, key) {
+  const previous = process.env.ADMIN_API_KEY;
+  if (key === undefined) delete process.env.ADMIN_API_KEY;
+  else process.env.ADMIN_API_KEY = key;
+  t.after(() => {
+    if (previous === undefined) delete process.env.ADMIN_API_KEY;
+    else process.env.ADMIN_API_KEY = previous;
+  });
+}
+
+const fakeRequest = (headers = {}) => ({ id: 'req', get: (name) => headers[name.toLowerCase()] });
+
+function runGuard(req) {
+  const res = {
+    statusCode: 200,
+    status(code) { this.statusCode = code; return this; },
+    json(body) { this.body = body; return this; }
+  };
+  let passed = false;
+  requireAdmin(req, res, () => { passed = true; });
+  return { passed, res };
+}
+
+test('requireAdmin is closed when no key is configured', (t) => {
+  withAdminKey(t, undefined);
+  const { passed, res } = runGuard(fakeRequest({ authorization: 'Bearer anything' }));
+  assert.equal(passed, false);
+  assert.equal(res.statusCode, 403);
+});
+
+test('requireAdmin accepts a Bearer token or X-API-Key', (t) => {
+  withAdminKey(t, ADMIN_KEY);
+  assert.equal(runGuard(fakeRequest({ authorization: `Bearer ${ADMIN_KEY}` })).passed, true);
+  assert.equal(runGuard(fakeRequest({ 'x-api-key': ADMIN_KEY })).passed, true);
+
+  const wrong = runGuard(fakeRequest({ authorization: 'Bearer nope' }));
+  assert.equal(wrong.passed, false);
+  assert.equal(wrong.res.statusCode, 401);
+  assert.equal(isAdminRequest(fakeRequest({ 'x-api-key': `${ADMIN_KEY}x` })), false);
+  assert.equal(isAdminRequest(fakeRequest()), false);
+});
+
+async function startApp(t) {
+  ['log', 'warn', 'error'].forEach(level => t.mock.method(console, level, () => {}));
+  const indexManager = new IndexManager();
+  indexManager.updateIndices([{ symbol: 'NIFTY', price: 25000 }]);
+  const server = buildApp({ layers: { rateLimit: false } }, { indexManager }).listen(0);
+  await once(server, 'listening');
+  t.after(() => server.close());
+
+  const baseUrl = `http://127.0.0.1:${server.address().port}/api`;
+  const headers = { 'Content-Type': 'application/json', 'X-API-Key': ADMIN_KEY };
+  return {
+    post: (body) => fetch(`${baseUrl}/admin/incidents`, { method: 'POST', headers, body: JSON.stringify(body) }),
+    resolve: (id) => fetch(`${baseUrl}/admin/incidents/${id}`, { method: 'DELETE', headers }),
+    status: async () => (await fetch(`${baseUrl}/status`)).json()
+  };
+}
+
+test('incident payloads are validated', async (t) => {
+  withAdminKey(t, ADMIN_KEY);
+  const api = await startApp(t);
+
+  for (const body of [{}, { title: ' ' }, { title: 'x'.repeat(201) }, { title: 'ok', message: 5 }, { title: 'ok', severity: 'huge' }]) {
+    const response = await api.post(body);
+    assert.equal(response.status, 400, JSON.stringify(body));
+    assert.equal((await response.json()).error, 'Invalid incident');
+  }
+
+  const created = await api.post({ title: '  Delayed data  ' });
+  assert.equal(created.status, 201);
+  assert.deepEqual(
+    (({ title, severity, resolvedAt }) => ({ title, severity, resolvedAt }))(await created.json()),
+    { title: 'Delayed data', severity: 'minor', resolvedAt: null }
+  );
+  assert.equal((await api.resolve('missing')).status, 404);
+});
+
+test('open incidents roll up into the overall status', async (t) => {
+  withAdminKey(t, ADMIN_KEY);
+  const api = await startApp(t);
+
+  assert.equal((await api.status()).status, 'operational');
+
+  await api.post({ title: 'Minor', severity: 'minor' });
+  assert.equal((await api.status()).status, 'operational');
+
+  const major = await (await api.post({ title: 'Major', severity: 'major' })).json();
+  assert.equal((await api.status()).status, 'degraded');
+
+  const critical = await (await api.post({ title: 'Critical', severity: 'critical' })).json();
+  assert.equal((await api.status()).status, 'down');
+
+  await api.resolve(critical.id);
+  assert.equal((await api.status()).status, 'degraded');
+  await api.resolve(major.id);
+
+  const status = await api.status();
+  assert.equal(status.status, 'operational');
+  assert.equal(status.incidents.length, 3);
+});
@@ -0,0 +1,57 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { once } from 'events';
+import { buildApp, parseDisabledLayers } from '../app.js';
+import { IndexManager } from '../scraper/indexManager.js';
+
+async function startApp(t, config = {}) {
+  ['log', 'warn'].forEach(level => t.mock.method(console, level, () => {}));
+  const indexManager = new IndexManager();
+  indexManager.updateIndices([{ symbol: 'NIFTY', price: 25000, exchange: 'NSE' }]);
+
+  const server = buildApp({ layers: { rateLimit: false }, ...config }, { indexManager }).listen(0);
+  await once(server, 'listening');
+  t.after(() => server.close());
+  return `http://127.0.0.1:${server.address().port}`;
+}
+
+test('buildApp rejects a missing index manager', () => {
+  assert.throws(() => buildApp(), /requires an indexManager/);
+});
+
+test('health check reports the scraper state', async (t) => {
+  const baseUrl = await startApp(t);
+  const response = await fetch(`${baseUrl}/api/health`);
+  const body = await response.json();
+
+  assert.equal(response.status, 200);
+  assert.ok(response.headers.get('x-request-id'));
+  assert.equal(body.status, 'ok');
+  assert.equal(body.scraper.indicesCount, 1);
+});
+
+test('indices are served under /api/market-data', async (t) => {
+  const baseUrl = await startApp(t);
+  const response = await fetch(`${baseUrl}/api/market-data/indices/all?symbols=NIFTY`);
+  const body = await response.json();
+
+  assert.equal(response.status, 200);
+  assert.equal(body.prices.NIFTY.price, 25000);
+  assert.equal(body.metadata.count, 1);
+});
+
+test('unknown routes return a JSON 404', async (t) => {
+  const baseUrl = await startApp(t);
+  const response = await fetch(`${baseUrl}/api/nope`);
+  const body = await response.json();
+
+  assert.equal(response.status, 404);
+  assert.equal(body.error, 'Not found');
+  assert.equal(body.request_id, response.headers.get('x-request-id'));
+});
+
+test('parseDisabledLayers keeps only known layers', (t) => {
+  t.mock.method(console, 'warn', () => {});
+  assert.deepEqual(parseDisabledLayers(undefined), {});
+  assert.deepEqual(parseDisabledLayers('rateLimit, timeout,nope'), { rateLimit: false, timeout: false });
+});
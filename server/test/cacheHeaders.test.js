@@ -0,0 +1,37 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { indicesCacheControl } from '../utils/cacheHeaders.js';
+
+const NOW = Date.parse('2025-11-05T10:00:00Z');
+
+const manager = ({ ageMs = 0, session = 'open', stale = false, scraped = true } = {}) => ({
+  lastSuccessfulScrape: scraped ? NOW - ageMs : null,
+  session,
+  isDataStale: () => stale
+});
+
+function withScrapeInterval(t, value) {
+  const previous = process.env.SCRAPE_INTERVAL_MS;
+  process.env.SCRAPE_INTERVAL_MS = value;
+  t.after(() => {
+    if (previous === undefined) delete process.env.SCRAPE_INTERVAL_MS;
+    else process.env.SCRAPE_INTERVAL_MS = previous;
+  });
+}
+
+test('nothing is cached before the first scrape', () => {
+  assert.equal(indicesCacheControl(manager({ scraped: false }), NOW), 'no-store');
+});
+
+test('shared caches keep fresh data until the next scrape is due', (t) => {
+  withScrapeInterval(t, '15000');
+  assert.equal(indicesCacheControl(manager({ ageMs: 4000 }), NOW), 'public, max-age=0, s-maxage=11, stale-while-revalidate=30');
+  // Overdue scrapes still allow one second of caching
+  assert.equal(indicesCacheControl(manager({ ageMs: 20000 }), NOW), 'public, max-age=0, s-maxage=1, stale-while-revalidate=30');
+});
+
+test('stale data is cached briefly, closed-session snapshots for longer', (t) => {
+  withScrapeInterval(t, '60000');
+  assert.equal(indicesCacheControl(manager({ stale: true }), NOW), 'public, max-age=0, s-maxage=5, stale-while-revalidate=120');
+  assert.equal(indicesCacheControl(manager({ session: 'closed', stale: true }), NOW), 'public, max-age=0, s-maxage=300, stale-while-revalidate=120');
+});
@@ -0,0 +1,39 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { buildDefaultIndices } from '../scraper/defaultIndices.js';
+import { createSymbolFilter } from '../utils/symbolFilter.js';
+
+const snapshot = {
+  capturedAt: '2025-11-05T10:00:00.000Z',
+  indices: [
+    { symbol: 'NIFTY', name: 'Nifty 50', exchange: 'NSE', price: 25597.65, change: -12.5, percent_change: -0.05 },
+    { symbol: 'UKX', name: 'FTSE 100', exchange: 'LSE', price: 9714.96, change: 20.1, percent_change: 0.21 }
+  ]
+};
+
+test('snapshots are served in the getAllIndices shape', () => {
+  const data = buildDefaultIndices(snapshot);
+
+  assert.deepEqual(data.metadata, {
+    lastUpdated: Date.parse(snapshot.capturedAt),
+    lastSuccessfulScrape: null,
+    status: 'fallback',
+    count: 2,
+    degraded: true
+  });
+  assert.equal(data.prices.NIFTY.change, -12.5);
+  assert.equal(data.prices.NIFTY.additional_data.exchange, 'NSE');
+  assert.equal(data.prices.NIFTY.additional_data.country, 'IN');
+});
+
+test('allow/deny lists apply to the snapshot', () => {
+  const data = buildDefaultIndices(snapshot, createSymbolFilter({ deny: 'exchange:LSE' }));
+  assert.deepEqual(Object.keys(data.prices), ['NIFTY']);
+  assert.equal(data.metadata.count, 1);
+});
+
+test('missing, empty and synthetic snapshots are not served', () => {
+  assert.equal(buildDefaultIndices(null), null);
+  assert.equal(buildDefaultIndices({ capturedAt: snapshot.capturedAt, indices: [] }), null);
+  assert.equal(buildDefaultIndices({ ...snapshot, synthetic: true }), null);
+});
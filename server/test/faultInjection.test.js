@@ -0,0 +1,34 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { getFaultInjectionConfig } from '../utils/faultInjection.js';
+
+test('disabled unless FAULT_INJECTION=true', () => {
+  assert.equal(getFaultInjectionConfig({}), null);
+  assert.equal(getFaultInjectionConfig({ FAULT_INJECTION: '1' }), null);
+});
+
+test('refused in production without the explicit override', (t) => {
+  const warn = t.mock.method(console, 'warn', () => {});
+  assert.equal(getFaultInjectionConfig({ FAULT_INJECTION: 'true', NODE_ENV: 'production' }), null);
+  assert.equal(warn.mock.callCount(), 1);
+
+  assert.deepEqual(
+    getFaultInjectionConfig({ FAULT_INJECTION: 'true', NODE_ENV: 'production', FAULT_INJECTION_ALLOW_PRODUCTION: 'true' }),
+    { latencyMs: 0, errorRate: 0, malformedRate: 0 }
+  );
+});
+
+test('rates are clamped to 0..1 and latency to >= 0', () => {
+  assert.deepEqual(getFaultInjectionConfig({
+    FAULT_INJECTION: 'true',
+    FAULT_LATENCY_MS: '-5',
+    FAULT_ERROR_RATE: '1.5',
+    FAULT_MALFORMED_RATE: 'abc'
+  }), { latencyMs: 0, errorRate: 1, malformedRate: 0 });
+
+  assert.deepEqual(getFaultInjectionConfig({
+    FAULT_INJECTION: 'true',
+    FAULT_LATENCY_MS: '250',
+    FAULT_ERROR_RATE: '0.2'
+  }), { latencyMs: 250, errorRate: 0.2, malformedRate: 0 });
+});
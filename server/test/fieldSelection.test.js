@@ -0,0 +1,34 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { parseFields, pickFields } from '../utils/fieldSelection.js';
+
+test('parseFields trims, dedupes and accepts repeated params', () => {
+  assert.equal(parseFields(undefined), null);
+  assert.equal(parseFields(' , '), null);
+  assert.deepEqual(parseFields(' price, ,change,price'), ['price', 'change']);
+  assert.deepEqual(parseFields(['price', 'additional_data.name']), ['price', 'additional_data.name']);
+});
+
+test('pickFields selects top-level and dotted paths', () => {
+  const record = {
+    symbol: 'NIFTY',
+    price: 25000,
+    change: 10,
+    additional_data: { name: 'Nifty 50', exchange: 'NSE', anomaly: null }
+  };
+
+  assert.deepEqual(pickFields(record, ['price', 'additional_data.name']), {
+    price: 25000,
+    additional_data: { name: 'Nifty 50' }
+  });
+  assert.deepEqual(pickFields(record, ['price'], ['symbol']), { symbol: 'NIFTY', price: 25000 });
+  assert.deepEqual(pickFields(record, ['additional_data.anomaly']), { additional_data: { anomaly: null } });
+});
+
+test('pickFields skips missing paths and passes through without a selection', () => {
+  const record = { price: 1, additional_data: { name: 'X' } };
+
+  assert.deepEqual(pickFields(record, ['volume', 'additional_data.name.first', 'price.value']), {});
+  assert.equal(pickFields(record, null), record);
+  assert.equal(pickFields(null, ['price']), null);
+});
@@ -0,0 +1,89 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { once } from 'events';
+import { buildApp } from '../app.js';
+import { IndexManager } from '../scraper/indexManager.js';
+
+const quiet = (t) => ['log', 'warn', 'error'].forEach(level => t.mock.method(console, level, () => {}));
+
+function createManager(t, ageMs) {
+  quiet(t);
+  const indexManager = new IndexManager();
+  indexManager.updateIndices([{ symbol: 'NIFTY', price: 25000 }]);
+  indexManager.lastSuccessfulScrape = Date.now() - ageMs;
+  indexManager.maxAgeMs = 60 * 1000;
+  indexManager.maxServeAgeMs = 10 * 60 * 1000;
+  return indexManager;
+}
+
+async function startApp(t, indexManager) {
+  const server = buildApp({ layers: { rateLimit: false } }, { indexManager }).listen(0);
+  await once(server, 'listening');
+  t.after(() => server.close());
+  return `http://127.0.0.1:${server.address().port}/api/market-data`;
+}
+
+const ROUTES = ['/indices/all', '/indices/search?q=nifty', '/indices/NIFTY'];
+
+test('data is stale after INDICES_MAX_AGE_MS and expired after INDICES_MAX_SERVE_AGE_MS', (t) => {
+  assert.equal(createManager(t, 1000).isDataStale(), false);
+  assert.equal(createManager(t, 2 * 60 * 1000).isDataStale(), true);
+  assert.equal(createManager(t, 2 * 60 * 1000).isDataExpired(), false);
+  assert.equal(createManager(t, 20 * 60 * 1000).isDataExpired(), true);
+
+  const unlimited = createManager(t, 20 * 60 * 1000);
+  unlimited.maxServeAgeMs = 0;
+  assert.equal(unlimited.isDataExpired(), false);
+});
+
+test('concurrent refreshIfStale calls share one refresh', async (t) => {
+  const indexManager = createManager(t, 2 * 60 * 1000);
+  let finish;
+  let calls = 0;
+  indexManager.setStaleHandler(() => {
+    calls++;
+    return new Promise(resolve => { finish = resolve; });
+  });
+
+  assert.equal(indexManager.refreshIfStale(), true);
+  assert.equal(indexManager.refreshIfStale(), true);
+  const pending = indexManager.pendingRefresh;
+  await Promise.resolve();
+  assert.equal(calls, 1);
+
+  finish();
+  await pending;
+  assert.equal(indexManager.pendingRefresh, null);
+});
+
+test('fresh data triggers no refresh', (t) => {
+  const indexManager = createManager(t, 1000);
+  indexManager.setStaleHandler(() => assert.fail('refreshed fresh data'));
+  assert.equal(indexManager.refreshIfStale(), false);
+});
+
+test('every indices route refuses expired data with 503', async (t) => {
+  const indexManager = createManager(t, 20 * 60 * 1000);
+  let refreshes = 0;
+  indexManager.setStaleHandler(async () => { refreshes++; });
+  const baseUrl = await startApp(t, indexManager);
+
+  for (const route of ROUTES) {
+    const response = await fetch(baseUrl + route);
+    assert.equal(response.status, 503, route);
+    assert.equal((await response.json()).error, 'Live data unavailable');
+  }
+  assert.ok(refreshes >= 1);
+});
+
+test('every indices route flags stale data', async (t) => {
+  const indexManager = createManager(t, 2 * 60 * 1000);
+  indexManager.setStaleHandler(async () => {});
+  const baseUrl = await startApp(t, indexManager);
+
+  for (const route of ROUTES) {
+    const response = await fetch(baseUrl + route);
+    assert.equal(response.status, 200, route);
+    assert.equal(response.headers.get('x-data-status'), 'stale', route);
+  }
+});
@@ -0,0 +1,125 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { IndexManager } from '../scraper/indexManager.js';
+
+const quiet = (t) => ['log', 'warn'].forEach(level => t.mock.method(console, level, () => {}));
+
+function createManager(t) {
+  quiet(t);
+  const manager = new IndexManager();
+  manager.maxJumpPercent = 20;
+  manager.anomalyConfirmations = 3;
+  return manager;
+}
+
+const feed = (manager, price) => manager.updateIndices([{ symbol: 'NIFTY', price }]);
+
+test('moves within the threshold are accepted', (t) => {
+  const manager = createManager(t);
+  feed(manager, 100);
+  feed(manager, 119);
+  assert.equal(manager.getIndex('NIFTY').price, 119);
+  assert.deepEqual(manager.getAllIndices().metadata.anomalies, []);
+});
+
+test('a jump beyond the threshold keeps the previous value', (t) => {
+  const manager = createManager(t);
+  feed(manager, 100);
+  feed(manager, 150);
+
+  const index = manager.getIndex('NIFTY');
+  assert.equal(index.price, 100);
+  assert.equal(index.additional_data.anomaly.rejectedPrice, 150);
+  assert.equal(index.additional_data.anomaly.previousPrice, 100);
+  assert.equal(index.additional_data.anomaly.jumpPercent, 50);
+  assert.deepEqual(manager.getAllIndices().metadata.anomalies, ['NIFTY']);
+});
+
+test('the new level is accepted after enough consecutive readings', (t) => {
+  const manager = createManager(t);
+  feed(manager, 100);
+  feed(manager, 150);
+  feed(manager, 150);
+  assert.equal(manager.getIndex('NIFTY').price, 100);
+
+  feed(manager, 150);
+  const index = manager.getIndex('NIFTY');
+  assert.equal(index.price, 150);
+  assert.equal(index.additional_data.anomaly, undefined);
+});
+
+test('a normal reading resets the confirmation streak', (t) => {
+  const manager = createManager(t);
+  feed(manager, 100);
+  feed(manager, 150);
+  feed(manager, 101);
+  assert.equal(manager.getIndex('NIFTY').price, 101);
+
+  feed(manager, 150);
+  feed(manager, 150);
+  assert.equal(manager.getIndex('NIFTY').price, 101);
+  feed(manager, 150);
+  assert.equal(manager.getIndex('NIFTY').price, 150);
+});
+
+test('alternating bad readings never confirm a level', (t) => {
+  const manager = createManager(t);
+  feed(manager, 100);
+  [10, 1000, 10, 1000, 10].forEach(price => feed(manager, price));
+  assert.equal(manager.getIndex('NIFTY').price, 100);
+  assert.equal(manager.getIndex('NIFTY').additional_data.anomaly.rejectedPrice, 10);
+});
+
+test('readings close to the pending level confirm it', (t) => {
+  const manager = createManager(t);
+  feed(manager, 100);
+  feed(manager, 150);
+  feed(manager, 155);
+  feed(manager, 152);
+  assert.equal(manager.getIndex('NIFTY').price, 152);
+});
+
+test('a zero threshold disables the check', (t) => {
+  const manager = createManager(t);
+  manager.maxJumpPercent = 0;
+  feed(manager, 100);
+  feed(manager, 500);
+  assert.equal(manager.getIndex('NIFTY').price, 500);
+});
+
+test('closed exchanges hold their end-of-day snapshot', (t) => {
+  const manager = createManager(t);
+  const feedBoth = (price) => manager.updateIndices([{ symbol: 'NIFTY', price }, { symbol: 'DJI', price }]);
+
+  feedBoth(100);
+  manager.setExchangeSession('NSE', 'closed');
+  manager.setExchangeSession('NYSE', 'open');
+  feedBoth(101);
+
+  assert.equal(manager.getIndex('NIFTY').price, 100);
+  assert.equal(manager.getIndex('NIFTY').additional_data.session, 'closed');
+  assert.equal(manager.getIndex('DJI').price, 101);
+  assert.equal(manager.getIndex('DJI').additional_data.session, 'open');
+  assert.equal(manager.session, 'open');
+
+  manager.setExchangeSession('NYSE', 'closed');
+  assert.equal(manager.session, 'closed');
+  assert.equal(manager.isDataStale(), false);
+
+  manager.setExchangeSession('NSE', 'open');
+  feedBoth(102);
+  assert.equal(manager.getIndex('NIFTY').price, 102);
+  assert.equal(manager.getIndex('DJI').price, 101);
+});
+
+test('closed snapshots survive scrapes that leave them out', (t) => {
+  const manager = createManager(t);
+  manager.updateIndices([{ symbol: 'NIFTY', price: 100 }, { symbol: 'DJI', price: 100 }]);
+  manager.setExchangeSession('NSE', 'closed');
+  manager.setExchangeSession('NYSE', 'open');
+  manager.updateIndices([{ symbol: 'DJI', price: 101 }]);
+
+  assert.equal(manager.getIndex('NIFTY').price, 100);
+  assert.equal(manager.getIndex('DJI').price, 101);
+  assert.equal(manager.getAllIndices().metadata.count, 2);
+});
@@ -0,0 +1,65 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { parseIndicesQuery, parseFieldsParam, REGIONS, SEARCH_RESULT_FIELDS } from '../utils/indicesQuery.js';
+
+const known = ['NIFTY', 'DJI', 'DAX'];
+const params = (errors) => errors.map(({ param }) => param);
+
+test('defaults when no parameters are given', () => {
+  assert.deepEqual(parseIndicesQuery({}, known), {
+    value: { symbols: null, countries: null, includeClosed: true, fields: null },
+    errors: []
+  });
+});
+
+test('symbols are upper-cased and must be known', () => {
+  assert.deepEqual(parseIndicesQuery({ symbols: 'nifty, DJI' }, known).value.symbols, new Set(['NIFTY', 'DJI']));
+  assert.deepEqual(params(parseIndicesQuery({ symbols: 'NIFTY,FOO' }, known).errors), ['symbols']);
+  assert.deepEqual(params(parseIndicesQuery({ symbols: ' , ' }, known).errors), ['symbols']);
+});
+
+test('region accepts groups and country codes', () => {
+  assert.deepEqual(parseIndicesQuery({ region: 'Europe' }, known).value.countries, new Set(REGIONS.europe));
+  assert.deepEqual(parseIndicesQuery({ region: 'in' }, known).value.countries, new Set(['IN']));
+  assert.deepEqual(params(parseIndicesQuery({ region: 'mars' }, known).errors), ['region']);
+});
+
+test('include_closed must be a boolean', () => {
+  assert.equal(parseIndicesQuery({ include_closed: '0' }, known).value.includeClosed, false);
+  assert.equal(parseIndicesQuery({ include_closed: 'TRUE' }, known).value.includeClosed, true);
+  assert.deepEqual(params(parseIndicesQuery({ include_closed: 'maybe' }, known).errors), ['include_closed']);
+});
+
+test('fields are validated against the /indices/all record shape', () => {
+  assert.deepEqual(parseIndicesQuery({ fields: 'price,additional_data.session' }, known).value.fields, ['price', 'additional_data.session']);
+  // /indices/all is keyed by symbol, so symbol is not a field there
+  assert.deepEqual(params(parseIndicesQuery({ fields: 'symbol' }, known).errors), ['fields']);
+});
+
+test('every invalid parameter is reported', () => {
+  const { errors } = parseIndicesQuery({ symbols: 'FOO', region: 'mars', include_closed: 'x', fields: 'volume' }, known);
+  assert.deepEqual(params(errors), ['symbols', 'region', 'include_closed', 'fields']);
+});
+
+test('unknown parameters are rejected, debug is allowed', () => {
+  const { errors } = parseIndicesQuery({ symbol: 'NIFTY', debug: 'timings' }, known);
+  assert.deepEqual(params(errors), ['symbol']);
+  assert.match(errors[0].message, /Allowed: symbols, region, include_closed, fields, debug/);
+});
+
+test('object values from bracket syntax are rejected', () => {
+  const { errors } = parseIndicesQuery({
+    symbols: { a: 'NIFTY' },
+    region: ['IN', 'US'],
+    include_closed: { x: 'true' },
+    fields: { price: '' }
+  }, known);
+  assert.deepEqual(params(errors), ['symbols', 'region', 'include_closed', 'fields']);
+  assert.deepEqual(parseIndicesQuery({ symbols: ['NIFTY', 'DJI'] }, known).value.symbols, new Set(['NIFTY', 'DJI']));
+});
+
+test('parseFieldsParam lists the allowed fields on error', () => {
+  assert.deepEqual(parseFieldsParam('symbol,price', SEARCH_RESULT_FIELDS), { fields: ['symbol', 'price'], errors: [] });
+  const { errors } = parseFieldsParam('volume', SEARCH_RESULT_FIELDS);
+  assert.match(errors[0].message, /Unknown fields: volume\. Allowed: symbol, name/);
+});
@@ -0,0 +1,53 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { once } from 'events';
+import { buildApp } from '../app.js';
+import { IndexManager } from '../scraper/indexManager.js';
+import { negotiateLocale, getLocaleHints } from '../utils/localeHints.js';
+
+test('negotiateLocale picks the highest-q supported tag', () => {
+  assert.equal(negotiateLocale(undefined), 'en-US');
+  assert.equal(negotiateLocale('fr-FR;q=0.5, de-DE;q=0.9, *;q=1'), 'de-DE');
+  assert.equal(negotiateLocale('en-GB;q=0, hi-IN'), 'hi-IN');
+  assert.equal(negotiateLocale('not a tag!!, es-ES;q=0.1'), 'es-ES');
+});
+
+test('Indian locales use lakh grouping', () => {
+  const india = getLocaleHints('en-IN');
+  assert.equal(india.number.grouping, 'lakh');
+  assert.equal(india.number.example, '12,34,567.89');
+  assert.equal(india.currency.code, 'INR');
+
+  assert.equal(getLocaleHints('en-US').number.grouping, 'standard');
+});
+
+test('currency follows the locale region, including the whole eurozone', () => {
+  for (const locale of ['de-AT', 'nl-BE', 'pt-PT', 'en-IE', 'fi-FI']) {
+    assert.equal(getLocaleHints(locale).currency.code, 'EUR', locale);
+  }
+  assert.equal(getLocaleHints('ja-JP').currency.code, 'JPY');
+  assert.equal(getLocaleHints('de-DE', 'chf').currency.code, 'CHF');
+});
+
+test('index and exchange names fall back to English', () => {
+  const german = getLocaleHints('de-DE');
+  assert.equal(german.exchanges.NYSE, 'New Yorker Börse');
+  assert.equal(german.indices.NIFTY, 'Nifty 50');
+  assert.equal(getLocaleHints('ja-JP').exchanges.NYSE, 'New York Stock Exchange');
+});
+
+test('/api/locale keeps the cors Vary header', async (t) => {
+  const server = buildApp({ layers: { rateLimit: false } }, { indexManager: new IndexManager() }).listen(0);
+  await once(server, 'listening');
+  t.after(() => server.close());
+
+  const response = await fetch(`http://127.0.0.1:${server.address().port}/api/locale`, {
+    headers: { 'Accept-Language': 'de-AT', 'Origin': 'https://example.com' }
+  });
+  const vary = response.headers.get('vary').split(',').map(value => value.trim());
+
+  assert.equal(response.status, 200);
+  assert.ok(vary.includes('Origin'));
+  assert.ok(vary.includes('Accept-Language'));
+  assert.equal(response.headers.get('content-language'), 'de-AT');
+});
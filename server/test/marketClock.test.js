@@ -0,0 +1,61 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { getExchangeClock, isExchangeOpen, getExchangeSessions, parseExchangeList, EXCHANGE_HOURS } from '../utils/marketClock.js';
+
+const at = (iso) => Date.parse(iso);
+
+test('NYSE opens at 09:30 New York time on both sides of DST', () => {
+  // EDT (UTC-4) in July, EST (UTC-5) in January
+  assert.equal(isExchangeOpen('NYSE', at('2025-07-01T13:30:00Z')), true);
+  assert.equal(isExchangeOpen('NYSE', at('2025-07-01T13:29:00Z')), false);
+  assert.equal(isExchangeOpen('NYSE', at('2025-01-07T14:30:00Z')), true);
+  assert.equal(isExchangeOpen('NYSE', at('2025-01-07T14:29:00Z')), false);
+});
+
+test('countdowns before the open and during the session', () => {
+  const before = getExchangeClock('NYSE', at('2025-07-01T13:29:00Z'));
+  assert.equal(before.status, 'closed');
+  assert.equal(before.msUntilOpen, 60 * 1000);
+  assert.equal(before.nextOpen, '2025-07-01T13:30:00.000Z');
+  assert.equal(before.localTime, '2025-07-01T09:29:00');
+
+  const during = getExchangeClock('NSE', at('2025-11-05T03:45:00Z'));
+  assert.equal(during.status, 'open');
+  assert.equal(during.msUntilOpen, 0);
+  assert.equal(during.msUntilClose, 6 * 60 * 60 * 1000 + 15 * 60 * 1000);
+  assert.equal(during.nextClose, '2025-11-05T10:00:00.000Z');
+});
+
+test('next open skips the weekend and crosses the DST change', () => {
+  // US clocks moved forward on Sunday 2025-03-09
+  const friday = getExchangeClock('NYSE', at('2025-03-07T21:00:00Z'));
+  assert.equal(friday.status, 'closed');
+  assert.equal(friday.nextOpen, '2025-03-10T13:30:00.000Z');
+  assert.equal(friday.nextClose, '2025-03-10T20:00:00.000Z');
+
+  const saturday = getExchangeClock('LSE', at('2025-03-08T12:00:00Z'));
+  assert.equal(saturday.nextOpen, '2025-03-10T08:00:00.000Z');
+  assert.equal(saturday.msUntilOpen, at('2025-03-10T08:00:00Z') - at('2025-03-08T12:00:00Z'));
+});
+
+test('unknown exchanges have no clock', () => {
+  assert.equal(getExchangeClock('NOPE'), null);
+  assert.equal(isExchangeOpen('NOPE'), false);
+});
+
+test('getExchangeSessions counts the grace period after close as open', () => {
+  const now = at('2025-07-01T20:05:00Z');
+  assert.deepEqual(getExchangeSessions(['NYSE', 'NSE'], 0, now), { NYSE: 'closed', NSE: 'closed' });
+  assert.deepEqual(getExchangeSessions(['NYSE', 'NSE'], 10 * 60 * 1000, now), { NYSE: 'open', NSE: 'closed' });
+});
+
+test('parseExchangeList drops unknown ids and falls back to all exchanges', (t) => {
+  const warn = t.mock.method(console, 'warn', () => {});
+  const all = Object.keys(EXCHANGE_HOURS);
+
+  assert.deepEqual(parseExchangeList(undefined), all);
+  assert.deepEqual(parseExchangeList(' nse, NYSE '), ['NSE', 'NYSE']);
+  assert.deepEqual(parseExchangeList('NSE,NYSEE'), ['NSE']);
+  assert.equal(warn.mock.callCount(), 1);
+  assert.deepEqual(parseExchangeList('NYSEE'), all);
+});
@@ -0,0 +1,24 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { redactSecrets } from '../utils/redact.js';
+
+test('configured secrets are masked wherever they appear', () => {
+  const env = { ADMIN_API_KEY: 'admin-secret', CDN_PURGE_TOKEN: 'cdn-secret' };
+  assert.equal(
+    redactSecrets('key admin-secret rejected, purge with cdn-secret failed', env),
+    'key [REDACTED] rejected, purge with [REDACTED] failed'
+  );
+});
+
+test('bearer credentials and key query parameters are masked', () => {
+  assert.equal(redactSecrets('Authorization: Bearer abc.def', {}), 'Authorization: Bearer [REDACTED]');
+  assert.equal(
+    redactSecrets('GET https://cdn.example/purge?api_key=xyz&zone=1&token=t0k', {}),
+    'GET https://cdn.example/purge?api_key=[REDACTED]&zone=1&token=[REDACTED]'
+  );
+});
+
+test('text without secrets is unchanged', () => {
+  assert.equal(redactSecrets('Error: boom\n    at handler (app.js:1:1)', {}), 'Error: boom\n    at handler (app.js:1:1)');
+  assert.equal(redactSecrets(undefined, {}), 'undefined');
+});
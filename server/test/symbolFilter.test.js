@@ -0,0 +1,46 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { parseSymbolRules, createSymbolFilter } from '../utils/symbolFilter.js';
+
+test('parseSymbolRules splits symbols, countries and exchanges', (t) => {
+  const warn = t.mock.method(console, 'warn', () => {});
+
+  assert.equal(parseSymbolRules(undefined), null);
+  assert.equal(parseSymbolRules('  '), null);
+  assert.deepEqual(parseSymbolRules('nifty, country:us,exchange:lse,sector:tech'), {
+    symbols: new Set(['NIFTY']),
+    countries: new Set(['US']),
+    exchanges: new Set(['LSE'])
+  });
+  assert.equal(warn.mock.callCount(), 1);
+});
+
+test('everything is allowed without lists', () => {
+  const isAllowed = createSymbolFilter();
+  assert.equal(isAllowed('NIFTY'), true);
+  assert.equal(isAllowed('ANYTHING'), true);
+});
+
+test('allow list restricts by symbol and country', () => {
+  const isAllowed = createSymbolFilter({ allow: 'NIFTY,country:US' });
+  assert.equal(isAllowed('NIFTY'), true);
+  assert.equal(isAllowed('DJI'), true);
+  assert.equal(isAllowed('SENSEX'), false);
+  assert.equal(isAllowed('DAX'), false);
+});
+
+test('deny wins over allow', () => {
+  const isAllowed = createSymbolFilter({ allow: 'country:US', deny: 'VIX' });
+  assert.equal(isAllowed('DJI'), true);
+  assert.equal(isAllowed('VIX'), false);
+});
+
+test('exchange rules match the scraped exchange, else the country exchange', () => {
+  const isAllowed = createSymbolFilter({ deny: 'exchange:LSE,exchange:BSE' });
+  assert.equal(isAllowed('UKX'), false);
+  assert.equal(isAllowed('UKX', 'Unknown'), false);
+  assert.equal(isAllowed('SENSEX', 'bse'), false);
+  // India maps to NSE, so without a scraped exchange SENSEX is not on BSE
+  assert.equal(isAllowed('SENSEX'), true);
+  assert.equal(isAllowed('DAX'), true);
+});
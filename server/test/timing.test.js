@@ -0,0 +1,78 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { TimingContext, timingMiddleware, getTimings } from '../utils/timing.js';
+
+const ADMIN_KEY = 'test-admin-key';
+
+function withAdminKey(t) {
+  const previous = process.env.ADMIN_API_KEY;
+  process.env.ADMIN_API_KEY = ADMIN_KEY;
+  t.after(() => {
+    if (previous === undefined) delete process.env.ADMIN_API_KEY;
+    else process.env.ADMIN_API_KEY = previous;
+  });
+}
+
+// Run the middleware, then respond the way a route does
+function respond({ query = {}, headers = {} }, body) {
+  const req = { query, get: (name) => headers[name.toLowerCase()] };
+  const res = {
+    headers: {},
+    set(name, value) {
+      if (typeof name === 'object') Object.assign(this.headers, name);
+      else this.headers[name] = value;
+      return this;
+    },
+    vary(field) {
+      this.headers.Vary = this.headers.Vary ? `${this.headers.Vary}, ${field}` : field;
+      return this;
+    },
+    json(payload) { this.body = payload; return this; }
+  };
+
+  timingMiddleware(req, res, () => {});
+  getTimings(req).measure('lookup', () => null);
+  res.set('Cache-Control', 'public, max-age=0, s-maxage=10');
+  res.json(body);
+  return res;
+}
+
+test('admin debug=timings responses carry the breakdown and are not shareable', (t) => {
+  withAdminKey(t);
+  const res = respond({ query: { debug: 'timings' }, headers: { authorization: `Bearer ${ADMIN_KEY}` } }, { ok: true });
+
+  assert.match(res.headers['Server-Timing'], /^lookup;dur=[\d.]+, serialization;dur=[\d.]+, total;dur=[\d.]+$/);
+  assert.deepEqual(res.body.debug.timings.phases.map(({ name }) => name), ['lookup', 'serialization']);
+  assert.equal(res.body.ok, true);
+  assert.equal(res.headers['Cache-Control'], 'private, no-store');
+  assert.equal(res.headers.Vary, 'Authorization, X-API-Key');
+});
+
+test('non-admin debug=timings gets no breakdown and is not cached', (t) => {
+  withAdminKey(t);
+  const res = respond({ query: { debug: 'timings' }, headers: { authorization: 'Bearer wrong' } }, { ok: true });
+
+  assert.equal(res.headers['Server-Timing'], undefined);
+  assert.deepEqual(res.body, { ok: true });
+  assert.equal(res.headers['Cache-Control'], 'private, no-store');
+  assert.equal(res.headers.Vary, 'Authorization, X-API-Key');
+});
+
+test('requests without debug=timings are untouched', (t) => {
+  withAdminKey(t);
+  const res = respond({ headers: { authorization: `Bearer ${ADMIN_KEY}` } }, { ok: true });
+
+  assert.equal(res.headers['Server-Timing'], undefined);
+  assert.equal(res.headers['Cache-Control'], 'public, max-age=0, s-maxage=10');
+  assert.equal(res.headers.Vary, undefined);
+});
+
+test('TimingContext records phases in order', () => {
+  const timings = new TimingContext();
+  assert.equal(timings.measure('parse', () => 42), 42);
+  timings.start('filter')();
+
+  const { phases, totalMs } = timings.toJSON();
+  assert.deepEqual(phases.map(({ name }) => name), ['parse', 'filter']);
+  assert.ok(totalMs >= 0);
+});
@@ -0,0 +1,43 @@
+import { timingSafeEqual } from 'crypto';
+
+/**
+ * Express middleware guarding admin routes with the ADMIN_API_KEY env var.
+ * Accepts "Authorization: Bearer <key>" or "X-API-Key: <key>". When no key is
+ * configured, admin routes are disabled entirely.
+ */
+export function requireAdmin(req, res, next) {
+  const adminKey = process.env.ADMIN_API_KEY;
+  if (!adminKey) {
+    return res.status(403).json({
+      error: 'Admin API disabled',
+      message: 'Set ADMIN_API_KEY to enable admin endpoints',
+      request_id: req.id
+    });
+  }
+
+  if (!isAdminRequest(req)) {
+    return res.status(401).json({
+      error: 'Unauthorized',
+      message: 'A valid admin API key is required',
+      request_id: req.id
+    });
+  }
+
+  next();
+}
+
+/**
+ * Check whether a request carries the admin API key
+ * @param {Request} req - Express request
+ * @returns {boolean}
+ */
+export function isAdminRequest(req) {
+  const adminKey = process.env.ADMIN_API_KEY;
+  if (!adminKey) return false;
+
+  const header = req.get('authorization') || '';
+  const provided = header.startsWith('Bearer ') ? header.slice(7) : (req.get('x-api-key') || '');
+  const expected = Buffer.from(adminKey);
+  const actual = Buffer.from(provided);
+  return actual.length === expected.length && timingSafeEqual(actual, expected);
+}
@@ -0,0 +1,27 @@
+/**
+ * Cache-Control for indices responses, derived from data freshness so a CDN
+ * can absorb polling traffic. Browsers always revalidate (max-age=0); shared
+ * caches keep a response until the next scrape is due.
+ * @param {IndexManager} indexManager - Index manager instance
+ * @param {number} now - Epoch milliseconds
+ * @returns {string} - Cache-Control header value
+ */
+export function indicesCacheControl(indexManager, now = Date.now()) {
+  if (!indexManager.lastSuccessfulScrape) return 'no-store';
+
+  const intervalMs = Number(process.env.SCRAPE_INTERVAL_MS || 15000);
+  const revalidateSeconds = Math.ceil(intervalMs * 2 / 1000);
+
+  if (indexManager.session === 'closed') {
+    // End-of-day snapshot does not change until the next open
+    return `public, max-age=0, s-maxage=300, stale-while-revalidate=${revalidateSeconds}`;
+  }
+
+  if (indexManager.isDataStale()) {
+    return `public, max-age=0, s-maxage=5, stale-while-revalidate=${revalidateSeconds}`;
+  }
+
+  const ageMs = now - indexManager.lastSuccessfulScrape;
+  const ttlSeconds = Math.max(Math.floor((intervalMs - ageMs) / 1000), 1);
+  return `public, max-age=0, s-maxage=${ttlSeconds}, stale-while-revalidate=${revalidateSeconds}`;
+}
@@ -0,0 +1,146 @@
+/**
+ * Display names of tracked indices and exchanges by language. English is
+ * complete; other languages only list names that differ and fall back to
+ * English for the rest. Keys match INDEX_METADATA and EXCHANGE_HOURS.
+ */
+export const INDEX_NAMES = {
+  en: {
+    'NIFTY': 'Nifty 50',
+    'SENSEX': 'BSE Sensex',
+    'BANKNIFTY': 'Nifty Bank',
+    'INDIAVIX': 'India VIX',
+    'DJI': 'Dow Jones Industrial Average',
+    'S&P-500': 'S&P 500',
+    'IXIC': 'Nasdaq Composite',
+    'RUSSELL2000': 'Russell 2000',
+    'VIX': 'Cboe Volatility Index',
+    'TSX': 'S&P/TSX Composite',
+    'BOVESPA': 'Ibovespa',
+    'BMVIPC': 'S&P/BMV IPC',
+    'DAX': 'DAX 40',
+    'UKX': 'FTSE 100',
+    'CAC-40': 'CAC 40',
+    'EUROSTOXX50': 'Euro Stoxx 50',
+    'AEX': 'AEX',
+    'IBEX35': 'IBEX 35',
+    'FTSEMIB': 'FTSE MIB',
+    'SMI': 'Swiss Market Index'
+  },
+  hi: {
+    'NIFTY': 'निफ्टी 50',
+    'SENSEX': 'सेंसेक्स',
+    'BANKNIFTY': 'निफ्टी बैंक',
+    'INDIAVIX': 'इंडिया VIX'
+  },
+  de: {
+    'VIX': 'Cboe-Volatilitätsindex'
+  },
+  fr: {
+    'VIX': 'Indice de volatilité Cboe'
+  },
+  es: {
+    'DJI': 'Promedio Industrial Dow Jones',
+    'IXIC': 'Nasdaq Compuesto',
+    'VIX': 'Índice de volatilidad Cboe',
+    'TSX': 'S&P/TSX Compuesto'
+  },
+  it: {
+    'VIX': 'Indice di volatilità Cboe'
+  },
+  pt: {
+    'DJI': 'Média Industrial Dow Jones',
+    'IXIC': 'Nasdaq Composto',
+    'VIX': 'Índice de Volatilidade Cboe',
+    'TSX': 'S&P/TSX Composto'
+  }
+};
+
+export const EXCHANGE_NAMES = {
+  en: {
+    'NSE': 'National Stock Exchange of India',
+    'BSE': 'Bombay Stock Exchange',
+    'NYSE': 'New York Stock Exchange',
+    'NASDAQ': 'Nasdaq',
+    'CBOE': 'Cboe Global Markets',
+    'TSX': 'Toronto Stock Exchange',
+    'B3': 'B3 (Brasil, Bolsa, Balcão)',
+    'BMV': 'Mexican Stock Exchange',
+    'LSE': 'London Stock Exchange',
+    'XETRA': 'Xetra (Deutsche Börse)',
+    'EURONEXT': 'Euronext',
+    'BME': 'Bolsas y Mercados Españoles',
+    'BIT': 'Borsa Italiana',
+    'SIX': 'SIX Swiss Exchange'
+  },
+  hi: {
+    'NSE': 'नेशनल स्टॉक एक्सचेंज ऑफ इंडिया',
+    'BSE': 'बॉम्बे स्टॉक एक्सचेंज',
+    'NYSE': 'न्यूयॉर्क स्टॉक एक्सचेंज',
+    'NASDAQ': 'नैस्डैक',
+    'LSE': 'लंदन स्टॉक एक्सचेंज'
+  },
+  de: {
+    'NYSE': 'New Yorker Börse',
+    'TSX': 'Börse Toronto',
+    'BMV': 'Mexikanische Börse',
+    'LSE': 'Londoner Börse',
+    'BME': 'Spanische Börse (BME)'
+  },
+  fr: {
+    'NSE': 'Bourse nationale de l’Inde',
+    'BSE': 'Bourse de Bombay',
+    'NYSE': 'Bourse de New York',
+    'TSX': 'Bourse de Toronto',
+    'B3': 'B3 (Bourse du Brésil)',
+    'BMV': 'Bourse mexicaine des valeurs',
+    'LSE': 'Bourse de Londres',
+    'BME': 'Bourse espagnole (BME)',
+    'BIT': 'Bourse italienne'
+  },
+  es: {
+    'NSE': 'Bolsa Nacional de Valores de la India',
+    'BSE': 'Bolsa de Bombay',
+    'NYSE': 'Bolsa de Nueva York',
+    'TSX': 'Bolsa de Toronto',
+    'B3': 'B3 (Bolsa de Brasil)',
+    'BMV': 'Bolsa Mexicana de Valores',
+    'LSE': 'Bolsa de Londres',
+    'XETRA': 'Xetra (Bolsa de Fráncfort)',
+    'BIT': 'Bolsa de Milán',
+    'SIX': 'Bolsa suiza SIX'
+  },
+  it: {
+    'BSE': 'Borsa di Bombay',
+    'NYSE': 'Borsa di New York',
+    'TSX': 'Borsa di Toronto',
+    'B3': 'B3 (Borsa brasiliana)',
+    'BMV': 'Borsa messicana',
+    'LSE': 'Borsa di Londra',
+    'XETRA': 'Xetra (Borsa di Francoforte)',
+    'BME': 'Borsa spagnola (BME)'
+  },
+  pt: {
+    'BSE': 'Bolsa de Bombaim',
+    'NYSE': 'Bolsa de Valores de Nova York',
+    'TSX': 'Bolsa de Valores de Toronto',
+    'BMV': 'Bolsa Mexicana de Valores',
+    'LSE': 'Bolsa de Valores de Londres',
+    'BME': 'Bolsa de Espanha (BME)',
+    'BIT': 'Bolsa de Milão'
+  }
+};
+
+const localize = (table, language) => ({ ...table.en, ...table[language] });
+
+/**
+ * Index and exchange display names for a locale, falling back to English
+ * @param {string} locale - Locale from negotiateLocale
+ * @returns {{indices: Object, exchanges: Object}} - Names keyed by symbol and exchange id
+ */
+export function getDisplayNames(locale) {
+  const { language } = new Intl.Locale(locale);
+  return {
+    indices: localize(INDEX_NAMES, language),
+    exchanges: localize(EXCHANGE_NAMES, language)
+  };
+}
@@ -0,0 +1,102 @@
+/**
+ * Fault injection for resilience testing. Disabled unless FAULT_INJECTION=true,
+ * and refused under NODE_ENV=production unless FAULT_INJECTION_ALLOW_PRODUCTION=true.
+ */
+
+const rate = (value) => Math.min(Math.max(Number(value) || 0, 0), 1);
+const delay = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+
+/**
+ * Read fault injection settings from the environment
+ * @returns {Object|null} - {latencyMs, errorRate, malformedRate}, or null when disabled
+ */
+export function getFaultInjectionConfig(env = process.env) {
+  if (env.FAULT_INJECTION !== 'true') return null;
+  if (env.NODE_ENV === 'production' && env.FAULT_INJECTION_ALLOW_PRODUCTION !== 'true') {
+    console.warn('⚠️ FAULT_INJECTION ignored in production (set FAULT_INJECTION_ALLOW_PRODUCTION=true to override)');
+    return null;
+  }
+
+  return {
+    latencyMs: Math.max(Number(env.FAULT_LATENCY_MS) || 0, 0),
+    errorRate: rate(env.FAULT_ERROR_RATE),
+    malformedRate: rate(env.FAULT_MALFORMED_RATE)
+  };
+}
+
+/**
+ * Express middleware adding random latency (0..latencyMs), injected 503s and truncated JSON bodies
+ * @param {Object} config - From getFaultInjectionConfig
+ */
+export function faultInjectionMiddleware(config) {
+  return async (req, res, next) => {
+    if (config.latencyMs > 0) {
+      await delay(Math.random() * config.latencyMs);
+    }
+
+    if (Math.random() < config.errorRate) {
+      return res.status(503).json({
+        error: 'Injected fault',
+        message: 'Fault injection mode returned an error for this request',
+        request_id: req.id
+      });
+    }
+
+    if (Math.random() < config.malformedRate) {
+      res.status(200).type('application/json').send('{"prices": {"');
+      return;
+    }
+
+    next();
+  };
+}
+
+/**
+ * Wrap a scraper so scrapes can be delayed, fail, or feed corrupted values to the index manager
+ * @param {IndexScraper} scraper - Scraper instance, patched in place
+ * @param {Object} config - From getFaultInjectionConfig
+ * @returns {IndexScraper}
+ */
+export function wrapScraperWithFaults(scraper, config) {
+  const scrapeIndices = scraper.scrapeIndices.bind(scraper);
+
+  scraper.scrapeIndices = async (indexManager) => {
+    if (config.latencyMs > 0) {
+      await delay(Math.random() * config.latencyMs);
+    }
+
+    if (Math.random() < config.errorRate) {
+      const error = new Error('Injected scrape failure');
+      indexManager.markScrapeFailed(error);
+      throw error;
+    }
+
+    if (Math.random() < config.malformedRate) {
+      return scrapeIndices(withCorruptedUpdates(indexManager));
+    }
+
+    return scrapeIndices(indexManager);
+  };
+
+  return scraper;
+}
+
+/**
+ * Proxy an index manager so updates receive wildly scaled prices, mimicking parser errors
+ */
+function withCorruptedUpdates(indexManager) {
+  const updateIndices = (indicesData) => indexManager.updateIndices(
+    indicesData.map(index => ({
+      ...index,
+      price: index.price * (Math.random() < 0.5 ? 0.1 : 10)
+    }))
+  );
+
+  return new Proxy(indexManager, {
+    get(target, prop) {
+      if (prop === 'updateIndices') return updateIndices;
+      const value = target[prop];
+      return typeof value === 'function' ? value.bind(target) : value;
+    }
+  });
+}
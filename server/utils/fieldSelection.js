@@ -0,0 +1,51 @@
+/**
+ * Parse a ?fields=price,percent_change style query parameter
+ * @param {string|string[]|undefined} raw - Raw query value (repeated params arrive as an array)
+ * @returns {string[]|null} - Requested field paths, or null when no selection was made
+ */
+export function parseFields(raw) {
+  const value = Array.isArray(raw) ? raw.join(',') : raw;
+  if (typeof value !== 'string') return null;
+
+  const fields = value.split(',').map(field => field.trim()).filter(Boolean);
+  return fields.length > 0 ? [...new Set(fields)] : null;
+}
+
+/**
+ * Pick the requested fields from a record. Dotted paths select nested keys,
+ * e.g. "additional_data.name". Missing fields are left out of the result.
+ * @param {Object} record - Source object
+ * @param {string[]|null} fields - Field paths from parseFields
+ * @param {string[]} alwaysInclude - Fields kept regardless of the selection
+ * @returns {Object} - Record restricted to the selected fields
+ */
+export function pickFields(record, fields, alwaysInclude = []) {
+  if (!fields || !record || typeof record !== 'object') return record;
+
+  const result = {};
+  for (const path of [...alwaysInclude, ...fields]) {
+    const keys = path.split('.');
+    let source = record;
+    let found = true;
+
+    for (const key of keys) {
+      if (source === null || typeof source !== 'object' || !(key in source)) {
+        found = false;
+        break;
+      }
+      source = source[key];
+    }
+    if (!found) continue;
+
+    let target = result;
+    keys.slice(0, -1).forEach(key => {
+      if (typeof target[key] !== 'object' || target[key] === null) {
+        target[key] = {};
+      }
+      target = target[key];
+    });
+    target[keys[keys.length - 1]] = source;
+  }
+
+  return result;
+}
@@ -0,0 +1,128 @@
+import { INDEX_METADATA } from '../scraper/indexMetadata.js';
+import { parseFields } from './fieldSelection.js';
+
+// Region groups accepted by ?region= in addition to country codes
+export const REGIONS = {
+  asia: ['IN'],
+  americas: ['US', 'CA', 'BR', 'MX'],
+  europe: ['GB', 'DE', 'FR', 'NL', 'ES', 'IT', 'CH', 'EU']
+};
+
+const ADDITIONAL_DATA_FIELDS = ['name', 'exchange', 'country', 'currency', 'description', 'constituentCount', 'lastUpdated', 'anomaly', 'session'];
+
+// Selectable fields per response shape
+export const INDEX_FIELDS = [
+  'symbol', 'price', 'change', 'percent_change', 'additional_data',
+  ...ADDITIONAL_DATA_FIELDS.map(field => `additional_data.${field}`)
+];
+// /indices/all keys records by symbol, so symbol is not a field there
+export const ALL_INDICES_FIELDS = INDEX_FIELDS.filter(field => field !== 'symbol');
+export const SEARCH_RESULT_FIELDS = ['symbol', 'name', 'exchange', 'country', 'currency', 'price', 'change', 'percent_change'];
+
+// Parameters accepted by GET /indices/all; debug is handled by utils/timing.js
+export const INDICES_QUERY_PARAMS = ['symbols', 'region', 'include_closed', 'fields', 'debug'];
+
+// qs turns ?a[b]=c into objects; only plain and repeated values are accepted
+const isListValue = (value) => typeof value === 'string' ||
+  (Array.isArray(value) && value.every(item => typeof item === 'string'));
+
+const parseList = (value) => (Array.isArray(value) ? value.join(',') : value)
+  .split(',')
+  .map(item => item.trim())
+  .filter(Boolean);
+
+/**
+ * Parse and validate ?fields= against the fields a route can return
+ * @param {string|string[]|undefined} raw - Raw query value
+ * @param {string[]} allowed - Selectable fields
+ * @returns {{fields: string[]|null, errors: Array<{param: string, message: string}>}}
+ */
+export function parseFieldsParam(raw, allowed) {
+  if (raw !== undefined && !isListValue(raw)) {
+    return { fields: null, errors: [{ param: 'fields', message: 'Must be a comma-separated list' }] };
+  }
+
+  const fields = parseFields(raw);
+  const unknown = (fields || []).filter(field => !allowed.includes(field));
+  return {
+    fields,
+    errors: unknown.length > 0
+      ? [{ param: 'fields', message: `Unknown fields: ${unknown.join(', ')}. Allowed: ${allowed.join(', ')}` }]
+      : []
+  };
+}
+
+/**
+ * Parse query parameters of GET /indices/all
+ *
+ * - symbols: comma-separated index symbols (default: all)
+ * - region: country code (e.g. IN) or region group asia | americas | europe (default: all)
+ * - include_closed: whether to include indices whose market is closed (default: true)
+ * - fields: comma-separated field list (default: all)
+ * - debug: see utils/timing.js
+ *
+ * Any other parameter is reported as an error rather than ignored.
+ *
+ * @param {Object} query - req.query
+ * @param {Iterable<string>} knownSymbols - Symbols that may be requested
+ * @returns {{value: Object, errors: Array<{param: string, message: string}>}}
+ */
+export function parseIndicesQuery(query, knownSymbols) {
+  const errors = [];
+  const value = { symbols: null, countries: null, includeClosed: true, fields: null };
+
+  const known = new Set(knownSymbols);
+
+  Object.keys(query)
+    .filter(param => !INDICES_QUERY_PARAMS.includes(param))
+    .forEach(param => errors.push({
+      param,
+      message: `Unknown parameter. Allowed: ${INDICES_QUERY_PARAMS.join(', ')}`
+    }));
+
+  if (query.symbols !== undefined && !isListValue(query.symbols)) {
+    errors.push({ param: 'symbols', message: 'Must be a comma-separated list' });
+  } else if (query.symbols !== undefined) {
+    const symbols = parseList(query.symbols).map(symbol => symbol.toUpperCase());
+    const unknown = symbols.filter(symbol => !known.has(symbol));
+    if (symbols.length === 0) {
+      errors.push({ param: 'symbols', message: 'Must list at least one symbol' });
+    } else if (unknown.length > 0) {
+      errors.push({ param: 'symbols', message: `Unknown symbols: ${unknown.join(', ')}` });
+    } else {
+      value.symbols = new Set(symbols);
+    }
+  }
+
+  if (query.region !== undefined && typeof query.region !== 'string') {
+    errors.push({ param: 'region', message: 'Must be a single country code or region group' });
+  } else if (query.region !== undefined) {
+    const region = String(query.region).trim();
+    const countries = new Set(Object.values(INDEX_METADATA).map(meta => meta.country).filter(Boolean));
+    if (REGIONS[region.toLowerCase()]) {
+      value.countries = new Set(REGIONS[region.toLowerCase()]);
+    } else if (countries.has(region.toUpperCase())) {
+      value.countries = new Set([region.toUpperCase()]);
+    } else {
+      errors.push({
+        param: 'region',
+        message: `Unknown region "${region}". Use one of ${Object.keys(REGIONS).join(', ')} or a country code: ${[...countries].join(', ')}`
+      });
+    }
+  }
+
+  if (query.include_closed !== undefined && typeof query.include_closed !== 'string') {
+    errors.push({ param: 'include_closed', message: 'Must be true or false' });
+  } else if (query.include_closed !== undefined) {
+    const flag = String(query.include_closed).toLowerCase();
+    if (['true', '1'].includes(flag)) value.includeClosed = true;
+    else if (['false', '0'].includes(flag)) value.includeClosed = false;
+    else errors.push({ param: 'include_closed', message: 'Must be true or false' });
+  }
+
+  const fields = parseFieldsParam(query.fields, ALL_INDICES_FIELDS);
+  value.fields = fields.fields;
+  errors.push(...fields.errors);
+
+  return { value, errors };
+}
@@ -0,0 +1,88 @@
+import { INDEX_METADATA } from '../scraper/indexMetadata.js';
+import { getDisplayNames } from './displayNames.js';
+
+const DEFAULT_LOCALE = 'en-US';
+
+const EUROZONE = ['AT', 'BE', 'CY', 'DE', 'EE', 'ES', 'FI', 'FR', 'GR', 'HR', 'IE', 'IT', 'LT', 'LU', 'LV', 'MT', 'NL', 'PT', 'SI', 'SK'];
+
+// Currency implied by a locale's region when none is requested; other regions fall back to USD
+const REGION_CURRENCIES = {
+  IN: 'INR', US: 'USD', GB: 'GBP', CA: 'CAD', BR: 'BRL', MX: 'MXN', CH: 'CHF', LI: 'CHF',
+  AR: 'ARS', CL: 'CLP', CO: 'COP', PE: 'PEN', AU: 'AUD', NZ: 'NZD', JP: 'JPY', CN: 'CNY',
+  HK: 'HKD', SG: 'SGD', KR: 'KRW', ZA: 'ZAR', SE: 'SEK', NO: 'NOK', DK: 'DKK', PL: 'PLN',
+  CZ: 'CZK', HU: 'HUF', TR: 'TRY', IL: 'ILS', AE: 'AED', SA: 'SAR',
+  ...Object.fromEntries(EUROZONE.map(region => [region, 'EUR']))
+};
+
+/**
+ * Pick the best supported locale from an Accept-Language header
+ * @param {string|undefined} header - e.g. "hi-IN,hi;q=0.9,en;q=0.8"
+ * @returns {string} - Canonical locale tag
+ */
+export function negotiateLocale(header) {
+  if (!header) return DEFAULT_LOCALE;
+
+  const candidates = header.split(',')
+    .map(part => {
+      const [tag, ...params] = part.trim().split(';');
+      const q = params.map(p => p.trim()).find(p => p.startsWith('q='));
+      return { tag: tag.trim(), q: q ? Number(q.slice(2)) : 1 };
+    })
+    .filter(({ tag, q }) => tag && tag !== '*' && q > 0)
+    .sort((a, b) => b.q - a.q);
+
+  for (const { tag } of candidates) {
+    try {
+      const [supported] = Intl.NumberFormat.supportedLocalesOf([tag]);
+      if (supported) return supported;
+    } catch (error) {
+      // Malformed language tag, try the next one
+    }
+  }
+  return DEFAULT_LOCALE;
+}
+
+/**
+ * Formatting hints for rendering numbers, currency and dates in a locale
+ * @param {string} locale - Locale from negotiateLocale
+ * @param {string} [currency] - ISO 4217 code; derived from the locale's region when omitted
+ * @returns {Object}
+ */
+export function getLocaleHints(locale, currency) {
+  const region = new Intl.Locale(locale).maximize().region;
+  const currencyCode = (currency || REGION_CURRENCIES[region] || 'USD').toUpperCase();
+
+  const numberParts = new Intl.NumberFormat(locale).formatToParts(1234567.891);
+  const integerGroups = numberParts.filter(part => part.type === 'integer').map(part => part.value.length);
+  const currencyFormat = new Intl.NumberFormat(locale, { style: 'currency', currency: currencyCode });
+  const currencySymbol = new Intl.NumberFormat(locale, { style: 'currency', currency: currencyCode, currencyDisplay: 'narrowSymbol' })
+    .formatToParts(0)
+    .find(part => part.type === 'currency')?.value || currencyCode;
+
+  const regionNames = new Intl.DisplayNames([locale], { type: 'region' });
+  const countries = [...new Set(Object.values(INDEX_METADATA).map(meta => meta.country).filter(Boolean))];
+
+  return {
+    locale,
+    region: region || null,
+    number: {
+      decimalSeparator: numberParts.find(part => part.type === 'decimal')?.value || '.',
+      groupSeparator: numberParts.find(part => part.type === 'group')?.value || '',
+      // Indian grouping splits 1234567 as 12,34,567
+      grouping: integerGroups.length > 2 && integerGroups.slice(1, -1).every(size => size === 2) ? 'lakh' : 'standard',
+      example: new Intl.NumberFormat(locale).format(1234567.89)
+    },
+    currency: {
+      code: currencyCode,
+      symbol: currencySymbol,
+      name: new Intl.DisplayNames([locale], { type: 'currency' }).of(currencyCode),
+      fractionDigits: currencyFormat.resolvedOptions().maximumFractionDigits,
+      example: currencyFormat.format(123456.78)
+    },
+    date: {
+      example: new Intl.DateTimeFormat(locale, { dateStyle: 'medium', timeStyle: 'short', timeZone: 'UTC' }).format(new Date(Date.UTC(2025, 0, 31, 15, 30)))
+    },
+    countries: Object.fromEntries(countries.map(code => [code, regionNames.of(code)])),
+    ...getDisplayNames(locale)
+  };
+}
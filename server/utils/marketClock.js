@@ -0,0 +1,190 @@
+/**
+ * Regular trading sessions per exchange, in exchange local time.
+ * Exchange holidays are not modelled; a holiday is reported as a normal session.
+ */
+export const EXCHANGE_HOURS = {
+  'NSE': { open: '09:15', close: '15:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Asia/Kolkata' },
+  'BSE': { open: '09:15', close: '15:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Asia/Kolkata' },
+  'NYSE': { open: '09:30', close: '16:00', weekdays: [1, 2, 3, 4, 5], timezone: 'America/New_York' },
+  'NASDAQ': { open: '09:30', close: '16:00', weekdays: [1, 2, 3, 4, 5], timezone: 'America/New_York' },
+  'TSX': { open: '09:30', close: '16:00', weekdays: [1, 2, 3, 4, 5], timezone: 'America/Toronto' },
+  'LSE': { open: '08:00', close: '16:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Europe/London' },
+  'XETRA': { open: '09:00', close: '17:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Europe/Berlin' },
+  'EURONEXT': { open: '09:00', close: '17:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Europe/Paris' },
+  'SIX': { open: '09:00', close: '17:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Europe/Zurich' },
+  'B3': { open: '10:00', close: '17:00', weekdays: [1, 2, 3, 4, 5], timezone: 'America/Sao_Paulo' },
+  'BMV': { open: '08:30', close: '15:00', weekdays: [1, 2, 3, 4, 5], timezone: 'America/Mexico_City' },
+  'BME': { open: '09:00', close: '17:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Europe/Madrid' },
+  'BIT': { open: '09:00', close: '17:30', weekdays: [1, 2, 3, 4, 5], timezone: 'Europe/Rome' }
+};
+
+// Exchange whose session governs indices from each country. Every country in
+// INDEX_METADATA needs an entry; indices without one have an unknown session.
+export const COUNTRY_EXCHANGES = {
+  IN: 'NSE',
+  US: 'NYSE',
+  CA: 'TSX',
+  BR: 'B3',
+  MX: 'BMV',
+  GB: 'LSE',
+  DE: 'XETRA',
+  EU: 'XETRA',
+  FR: 'EURONEXT',
+  NL: 'EURONEXT',
+  ES: 'BME',
+  IT: 'BIT',
+  CH: 'SIX'
+};
+
+const toMinutes = (hhmm) => {
+  const [hours, minutes] = hhmm.split(':').map(Number);
+  return hours * 60 + minutes;
+};
+
+/**
+ * Wall-clock parts of an instant in a timezone
+ * @param {number} timestamp - Epoch milliseconds
+ * @param {string} timezone - IANA timezone
+ * @returns {Object} - {year, month, day, hour, minute, second} with month 1-12
+ */
+function getZonedParts(timestamp, timezone) {
+  const parts = new Intl.DateTimeFormat('en-US', {
+    timeZone: timezone,
+    hourCycle: 'h23',
+    year: 'numeric',
+    month: '2-digit',
+    day: '2-digit',
+    hour: '2-digit',
+    minute: '2-digit',
+    second: '2-digit'
+  }).formatToParts(new Date(timestamp));
+
+  const value = (type) => Number(parts.find(part => part.type === type).value);
+  return {
+    year: value('year'),
+    month: value('month'),
+    day: value('day'),
+    hour: value('hour'),
+    minute: value('minute'),
+    second: value('second')
+  };
+}
+
+/**
+ * Convert a wall-clock time in a timezone to epoch milliseconds
+ */
+function zonedTimeToTimestamp(year, month, day, minutesOfDay, timezone) {
+  const wallAsUtc = Date.UTC(year, month - 1, day, 0, minutesOfDay);
+  let guess = wallAsUtc;
+  // Two passes settle the offset, including across DST transitions
+  for (let i = 0; i < 2; i++) {
+    const p = getZonedParts(guess, timezone);
+    const offset = Date.UTC(p.year, p.month - 1, p.day, p.hour, p.minute, p.second) - guess;
+    guess = wallAsUtc - offset;
+  }
+  return guess;
+}
+
+/**
+ * Compute the session state of an exchange at a given instant
+ * @param {string} exchangeId - Key of EXCHANGE_HOURS
+ * @param {number} now - Epoch milliseconds
+ * @returns {Object|null} - Clock info, or null for unknown exchanges
+ */
+export function getExchangeClock(exchangeId, now = Date.now()) {
+  const market = EXCHANGE_HOURS[exchangeId];
+  if (!market) return null;
+
+  const local = getZonedParts(now, market.timezone);
+  const openMinutes = toMinutes(market.open);
+  const closeMinutes = toMinutes(market.close);
+
+  // Walk forward through calendar days in the exchange's timezone
+  const sessionOn = (offsetDays) => {
+    const date = new Date(Date.UTC(local.year, local.month - 1, local.day + offsetDays));
+    if (!market.weekdays.includes(date.getUTCDay())) return null;
+    const [y, m, d] = [date.getUTCFullYear(), date.getUTCMonth() + 1, date.getUTCDate()];
+    return {
+      open: zonedTimeToTimestamp(y, m, d, openMinutes, market.timezone),
+      close: zonedTimeToTimestamp(y, m, d, closeMinutes, market.timezone)
+    };
+  };
+
+  let current = null;
+  let next = null;
+  for (let offsetDays = 0; offsetDays <= 7 && !next; offsetDays++) {
+    const session = sessionOn(offsetDays);
+    if (!session) continue;
+    if (session.open <= now && now < session.close) {
+      current = session;
+    } else if (session.open > now) {
+      next = session;
+    }
+  }
+
+  const pad = (n) => String(n).padStart(2, '0');
+  return {
+    timezone: market.timezone,
+    localTime: `${local.year}-${pad(local.month)}-${pad(local.day)}T${pad(local.hour)}:${pad(local.minute)}:${pad(local.second)}`,
+    status: current ? 'open' : 'closed',
+    open: market.open,
+    close: market.close,
+    nextOpen: next ? new Date(next.open).toISOString() : null,
+    nextClose: new Date((current || next).close).toISOString(),
+    msUntilOpen: current ? 0 : (next ? next.open - now : null),
+    msUntilClose: (current || next).close - now
+  };
+}
+
+/**
+ * Check whether an exchange is currently in its regular session
+ * @param {string} exchangeId - Key of EXCHANGE_HOURS
+ * @param {number} now - Epoch milliseconds
+ * @returns {boolean}
+ */
+export function isExchangeOpen(exchangeId, now = Date.now()) {
+  return getExchangeClock(exchangeId, now)?.status === 'open';
+}
+
+/**
+ * Session of each of the given exchanges, counting graceMs after each close
+ * as still open so closing prints settle before a final snapshot
+ * @param {string[]} exchangeIds - Keys of EXCHANGE_HOURS (default: all)
+ * @param {number} graceMs - Time after close still treated as open
+ * @param {number} now - Epoch milliseconds
+ * @returns {Object} - {exchangeId: 'open'|'closed'}
+ */
+export function getExchangeSessions(exchangeIds = Object.keys(EXCHANGE_HOURS), graceMs = 0, now = Date.now()) {
+  return Object.fromEntries(exchangeIds.map(id => {
+    const open = isExchangeOpen(id, now) || (graceMs > 0 && isExchangeOpen(id, now - graceMs));
+    return [id, open ? 'open' : 'closed'];
+  }));
+}
+
+/**
+ * Parse an OFF_HOURS_EXCHANGES style comma-separated list of exchange ids.
+ * Unknown ids are dropped with a warning; an empty result falls back to all
+ * exchanges, since an empty list would read as permanently closed.
+ * @param {string|undefined} value - e.g. "NSE,NYSE"
+ * @returns {string[]} - Keys of EXCHANGE_HOURS
+ */
+export function parseExchangeList(value) {
+  const all = Object.keys(EXCHANGE_HOURS);
+  if (!value || !value.trim()) return all;
+
+  const ids = value.split(',')
+    .map(id => id.trim().toUpperCase())
+    .filter(id => {
+      if (id && !EXCHANGE_HOURS[id]) {
+        console.warn(`⚠️ Unknown exchange "${id}" in OFF_HOURS_EXCHANGES (known: ${all.join(', ')})`);
+        return false;
+      }
+      return Boolean(id);
+    });
+
+  if (ids.length === 0) {
+    console.warn('⚠️ OFF_HOURS_EXCHANGES lists no known exchange, tracking all exchanges');
+    return all;
+  }
+  return ids;
+}
@@ -0,0 +1,24 @@
+// Env vars whose values must never reach logs or error responses
+const SECRET_ENV_VARS = ['ADMIN_API_KEY', 'CDN_PURGE_TOKEN'];
+
+const REDACTED = '[REDACTED]';
+
+/**
+ * Mask secrets in text bound for logs: configured keys and tokens, bearer
+ * credentials and key/token query parameters
+ * @param {*} value - Message, stack trace or anything stringifiable
+ * @param {Object} env - Environment to read secret values from
+ * @returns {string}
+ */
+export function redactSecrets(value, env = process.env) {
+  let text = String(value);
+  for (const name of SECRET_ENV_VARS) {
+    if (env[name]) {
+      text = text.split(env[name]).join(REDACTED);
+    }
+  }
+
+  return text
+    .replace(/(Bearer\s+)[^\s"',;]+/gi, `$1${REDACTED}`)
+    .replace(/([?&](?:api[_-]?key|key|token)=)[^&\s"']+/gi, `$1${REDACTED}`);
+}
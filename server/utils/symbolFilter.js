@@ -0,0 +1,53 @@
+import { getIndexMetadata } from '../scraper/indexMetadata.js';
+import { COUNTRY_EXCHANGES } from './marketClock.js';
+
+/**
+ * Parse an allow/deny list such as "NIFTY,SENSEX,country:US,exchange:NSE"
+ * @param {string|undefined} value - Comma-separated symbols, country:<code> and exchange:<code> entries
+ * @returns {{symbols: Set<string>, countries: Set<string>, exchanges: Set<string>}|null} - null when the list is empty
+ */
+export function parseSymbolRules(value) {
+  if (!value || !value.trim()) return null;
+
+  const rules = { symbols: new Set(), countries: new Set(), exchanges: new Set() };
+  value.split(',').map(entry => entry.trim()).filter(Boolean).forEach(entry => {
+    const [kind, code] = entry.includes(':') ? entry.split(':', 2) : ['symbol', entry];
+    if (kind.toLowerCase() === 'country') {
+      rules.countries.add(code.trim().toUpperCase());
+    } else if (kind.toLowerCase() === 'exchange') {
+      rules.exchanges.add(code.trim().toUpperCase());
+    } else if (kind.toLowerCase() === 'symbol') {
+      rules.symbols.add(code.trim().toUpperCase());
+    } else {
+      console.warn(`⚠️  Ignoring unknown symbol rule "${entry}" (use SYMBOL, country:CODE or exchange:CODE)`);
+    }
+  });
+  return rules;
+}
+
+const matches = (rules, symbol, country, exchange) =>
+  rules.symbols.has(symbol.toUpperCase()) ||
+  (country && rules.countries.has(country)) ||
+  (exchange && rules.exchanges.has(exchange));
+
+/**
+ * Build a predicate enforcing allow and deny lists. Deny wins over allow;
+ * with no allow list every symbol not denied is allowed. exchange: rules
+ * match the scraped exchange, or the exchange of the index's country
+ * (COUNTRY_EXCHANGES) when none was scraped.
+ * @param {Object} lists - {allow, deny} raw list strings
+ * @returns {Function} - (symbol, exchange?) => boolean
+ */
+export function createSymbolFilter({ allow, deny } = {}) {
+  const allowRules = parseSymbolRules(allow);
+  const denyRules = parseSymbolRules(deny);
+
+  return (symbol, scrapedExchange) => {
+    const { country } = getIndexMetadata(symbol);
+    const exchange = typeof scrapedExchange === 'string' && scrapedExchange !== 'Unknown'
+      ? scrapedExchange.toUpperCase()
+      : COUNTRY_EXCHANGES[country];
+    if (denyRules && matches(denyRules, symbol, country, exchange)) return false;
+    return !allowRules || matches(allowRules, symbol, country, exchange);
+  };
+}
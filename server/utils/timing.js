@@ -0,0 +1,112 @@
+import { performance } from 'perf_hooks';
+import { isAdminRequest } from './adminAuth.js';
+
+/**
+ * Collects named phase durations for one request
+ */
+export class TimingContext {
+  constructor() {
+    this.startedAt = performance.now();
+    this.phases = [];
+  }
+
+  /**
+   * Start a phase
+   * @param {string} name - Phase name, e.g. "lookup"
+   * @returns {Function} - Call to end the phase
+   */
+  start(name) {
+    const startedAt = performance.now();
+    return () => {
+      this.phases.push({ name, durationMs: performance.now() - startedAt });
+    };
+  }
+
+  /**
+   * Time a synchronous function as a phase
+   * @param {string} name - Phase name
+   * @param {Function} fn - Work to time
+   * @returns {*} - Result of fn
+   */
+  measure(name, fn) {
+    const end = this.start(name);
+    try {
+      return fn();
+    } finally {
+      end();
+    }
+  }
+
+  toJSON() {
+    const round = (ms) => Number(ms.toFixed(3));
+    return {
+      phases: this.phases.map(({ name, durationMs }) => ({ name, durationMs: round(durationMs) })),
+      totalMs: round(performance.now() - this.startedAt)
+    };
+  }
+
+  /**
+   * Format as a Server-Timing header value
+   * @returns {string} - e.g. "lookup;dur=0.12, serialization;dur=0.3, total;dur=1.02"
+   */
+  toServerTimingHeader() {
+    const { phases, totalMs } = this.toJSON();
+    return [...phases.map(({ name, durationMs }) => `${name};dur=${durationMs}`), `total;dur=${totalMs}`].join(', ');
+  }
+}
+
+const NOOP_TIMINGS = {
+  start: () => () => {},
+  measure: (name, fn) => fn()
+};
+
+/**
+ * Timing context of a request; a no-op unless timings were requested
+ * @param {Request} req - Express request
+ */
+export function getTimings(req) {
+  return req.timings || NOOP_TIMINGS;
+}
+
+/**
+ * Express middleware enabling ?debug=timings for admin requests. Adds a
+ * Server-Timing header and a debug.timings block to JSON object responses.
+ * Requests without the admin key get normal responses.
+ *
+ * Any response to a debug=timings URL is marked private and uncacheable, so
+ * a shared cache neither stores the admin-only breakdown nor hands an admin
+ * a cached copy without it.
+ */
+export function timingMiddleware(req, res, next) {
+  if (req.query?.debug !== 'timings') {
+    return next();
+  }
+
+  const timings = isAdminRequest(req) ? new TimingContext() : null;
+  if (timings) {
+    req.timings = timings;
+  }
+
+  const json = res.json.bind(res);
+  res.json = (body) => {
+    // Set last so route-level Cache-Control does not win
+    res.set('Cache-Control', 'private, no-store');
+    res.vary('Authorization');
+    res.vary('X-API-Key');
+    if (!timings) {
+      return json(body);
+    }
+
+    timings.measure('serialization', () => JSON.stringify(body));
+    res.set({
+      'Server-Timing': timings.toServerTimingHeader(),
+      'Timing-Allow-Origin': '*'
+    });
+    if (body && typeof body === 'object' && !Array.isArray(body)) {
+      return json({ ...body, debug: { timings: timings.toJSON() } });
+    }
+    return json(body);
+  };
+
+  next();
+}